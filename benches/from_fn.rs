@@ -0,0 +1,59 @@
+//! Compares a hand-written iterator with the same iterator built with
+//! `from_fn`. Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+use internal_iterator::{InternalIterator, from_fn};
+
+const N: u64 = 100_000;
+
+struct Squares(u64);
+
+impl InternalIterator for Squares {
+    type Item = u64;
+
+    fn try_for_each<R, F>(self, mut f: F) -> ControlFlow<R>
+    where
+        F: FnMut(u64) -> ControlFlow<R>,
+    {
+        for i in 0..self.0 {
+            f(i * i)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+fn pipeline(iter: impl InternalIterator<Item = u64>) -> u64 {
+    iter.filter(|x| x % 3 == 0).map(|x| x / 3).sum()
+}
+
+/// Runs `f` repeatedly for about a second and prints the median time of a
+/// single run.
+fn bench(name: &str, mut f: impl FnMut() -> u64) -> u64 {
+    let result = f();
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(1) {
+        let sample = Instant::now();
+        assert_eq!(black_box(f()), result);
+        samples.push(sample.elapsed());
+    }
+    samples.sort();
+    println!("{:<16} {:>10.0?} per run ({} runs)", name, samples[samples.len() / 2], samples.len());
+    result
+}
+
+fn main() {
+    let hand_written = bench("hand-written", || pipeline(Squares(black_box(N))));
+    let dynamic = bench("from_fn", || {
+        let n = black_box(N);
+        pipeline(from_fn(|f| {
+            for i in 0..n {
+                f(i * i)?;
+            }
+            ControlFlow::Continue(())
+        }))
+    });
+    assert_eq!(dynamic, hand_written);
+}
@@ -1,6 +1,6 @@
 use core::ops::ControlFlow;
 
-use crate::{InternalIterator, IntoInternalIterator};
+use crate::{DoubleEndedInternalIterator, ExactSizeInternalIterator, InternalIterator, IntoInternalIterator};
 
 
 /// An iterator that links two iterators together, in a chain.
@@ -34,6 +34,47 @@ where
             (None, None) => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_lower, first_upper) = self.first.size_hint();
+        let (second_lower, second_upper) = self.second.size_hint();
+        let lower = first_lower.saturating_add(second_lower);
+        let upper = match (first_upper, second_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> DoubleEndedInternalIterator for Chain<A, B>
+where
+    A: DoubleEndedInternalIterator,
+    B: DoubleEndedInternalIterator<Item = A::Item>,
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { first, second } = self;
+        match second.try_rfor_each(&mut consumer) {
+            ControlFlow::Continue(()) => first.try_rfor_each(consumer),
+            br => br,
+        }
+    }
+}
+
+/// Unlike std, which does not implement `ExactSizeIterator` for `Chain`,
+/// [`len`](ExactSizeInternalIterator::len) is provided here, and panics if the
+/// combined length does not fit in `usize`.
+impl<A, B> ExactSizeInternalIterator for Chain<A, B>
+where
+    A: ExactSizeInternalIterator,
+    B: ExactSizeInternalIterator<Item = A::Item>,
+{
+    fn len(&self) -> usize {
+        self.first.len().checked_add(self.second.len()).expect("chain length overflows usize")
+    }
 }
 
 
@@ -56,6 +97,33 @@ where
     {
         self.iter.try_for_each(|item| consumer(item.clone()))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, T: 'a> DoubleEndedInternalIterator for Cloned<I>
+where
+    I: DoubleEndedInternalIterator<Item = &'a T>,
+    T: Clone,
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        self.iter.try_rfor_each(|item| consumer(item.clone()))
+    }
+}
+
+impl<'a, I, T: 'a> ExactSizeInternalIterator for Cloned<I>
+where
+    I: ExactSizeInternalIterator<Item = &'a T>,
+    T: Clone,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
 }
 
 
@@ -90,6 +158,160 @@ where
     fn nth(self, n: usize) -> Option<Self::Item> {
         self.iter.nth(n).copied()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, T: 'a> DoubleEndedInternalIterator for Copied<I>
+where
+    I: DoubleEndedInternalIterator<Item = &'a T>,
+    T: Copy,
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        self.iter.try_rfor_each(|&item| consumer(item))
+    }
+}
+
+impl<'a, I, T: 'a> ExactSizeInternalIterator for Copied<I>
+where
+    I: ExactSizeInternalIterator<Item = &'a T>,
+    T: Copy,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+
+/// An iterator that repeats the elements of `iter` endlessly.
+#[derive(Clone)]
+pub struct Cycle<I> {
+    pub(crate) iter: I,
+}
+
+impl<I> InternalIterator for Cycle<I>
+where
+    I: InternalIterator + Clone,
+{
+    type Item = I::Item;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        loop {
+            let mut empty = true;
+            self.iter.clone().try_for_each(|item| {
+                empty = false;
+                consumer(item)
+            })?;
+            if empty {
+                return ControlFlow::Continue(());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.iter.size_hint() {
+            (0, Some(0)) => (0, Some(0)),
+            (0, _) => (0, None),
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+
+/// An iterator that can be one of two different iterator types.
+///
+/// This is useful when a function needs to return different iterators from
+/// different branches. See [`InternalIterator::left_internal`] and
+/// [`InternalIterator::right_internal`].
+#[derive(Clone)]
+pub enum Either<L, R> {
+    /// The first iterator type.
+    Left(L),
+    /// The second iterator type.
+    Right(R),
+}
+
+impl<L, R> InternalIterator for Either<L, R>
+where
+    L: InternalIterator,
+    R: InternalIterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    fn try_for_each<T, C>(self, consumer: C) -> ControlFlow<T>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<T>
+    {
+        match self {
+            Either::Left(iter) => iter.try_for_each(consumer),
+            Either::Right(iter) => iter.try_for_each(consumer),
+        }
+    }
+
+    fn count(self) -> usize {
+        match self {
+            Either::Left(iter) => iter.count(),
+            Either::Right(iter) => iter.count(),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            Either::Left(iter) => iter.last(),
+            Either::Right(iter) => iter.last(),
+        }
+    }
+
+    fn nth(self, n: usize) -> Option<Self::Item> {
+        match self {
+            Either::Left(iter) => iter.nth(n),
+            Either::Right(iter) => iter.nth(n),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Either::Left(iter) => iter.size_hint(),
+            Either::Right(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<L, R> DoubleEndedInternalIterator for Either<L, R>
+where
+    L: DoubleEndedInternalIterator,
+    R: DoubleEndedInternalIterator<Item = L::Item>,
+{
+    fn try_rfor_each<T, C>(self, consumer: C) -> ControlFlow<T>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<T>
+    {
+        match self {
+            Either::Left(iter) => iter.try_rfor_each(consumer),
+            Either::Right(iter) => iter.try_rfor_each(consumer),
+        }
+    }
+}
+
+impl<L, R> ExactSizeInternalIterator for Either<L, R>
+where
+    L: ExactSizeInternalIterator,
+    R: ExactSizeInternalIterator<Item = L::Item>,
+{
+    fn len(&self) -> usize {
+        match self {
+            Either::Left(iter) => iter.len(),
+            Either::Right(iter) => iter.len(),
+        }
+    }
 }
 
 
@@ -125,6 +347,35 @@ where
         let value = self.iter.nth(n)?;
         Some((n, value))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedInternalIterator for Enumerate<I>
+where
+    I: DoubleEndedInternalIterator + ExactSizeInternalIterator,
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let mut idx = self.iter.len();
+        self.iter.try_rfor_each(|item| {
+            idx -= 1;
+            consumer((idx, item))
+        })
+    }
+}
+
+impl<I> ExactSizeInternalIterator for Enumerate<I>
+where
+    I: ExactSizeInternalIterator,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
 }
 
 
@@ -155,6 +406,31 @@ where
             }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, F> DoubleEndedInternalIterator for Filter<I, F>
+where
+    I: DoubleEndedInternalIterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut predicate } = self;
+        iter.try_rfor_each(|item| {
+            if predicate(&item) {
+                consumer(item)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+    }
 }
 
 
@@ -182,6 +458,11 @@ where
             None => ControlFlow::Continue(()),
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
 }
 
 
@@ -210,6 +491,53 @@ where
     }
 }
 
+impl<I, F, T, U> DoubleEndedInternalIterator for FlatMap<I, F>
+where
+    I: DoubleEndedInternalIterator,
+    F: FnMut(I::Item) -> U,
+    U: IntoInternalIterator<Item = T>,
+    U::IntoIter: DoubleEndedInternalIterator,
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut f } = self;
+        iter.try_rfor_each(|item| f(item).into_internal_iter().try_rfor_each(&mut consumer))
+    }
+}
+
+
+/// An iterator that flattens one level of nesting in an iterator of things
+/// that can be turned into iterators.
+#[derive(Clone)]
+pub struct Flatten<I> {
+    pub(crate) iter: I,
+}
+
+impl<I, U> InternalIterator for Flatten<I>
+where
+    I: InternalIterator<Item = U>,
+    U: IntoInternalIterator,
+{
+    type Item = U::Item;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        self.iter.try_for_each(|item| item.into_internal_iter().try_for_each(&mut consumer))
+    }
+
+    fn count(self) -> usize {
+        self.iter.fold(0, |count, item| count + item.into_internal_iter().count())
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.iter.fold(None, |last, item| item.into_internal_iter().last().or(last))
+    }
+}
+
 
 /// An iterator that calls a function with a reference to each element before
 /// yielding it.
@@ -236,6 +564,37 @@ where
             consumer(item)
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedInternalIterator for Inspect<I, F>
+where
+    I: DoubleEndedInternalIterator,
+    F: FnMut(&I::Item),
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut f } = self;
+        iter.try_rfor_each(|item| {
+            f(&item);
+            consumer(item)
+        })
+    }
+}
+
+impl<I, F> ExactSizeInternalIterator for Inspect<I, F>
+where
+    I: ExactSizeInternalIterator,
+    F: FnMut(&I::Item),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
 }
 
 
@@ -260,6 +619,168 @@ where
         let Self { iter, mut f } = self;
         iter.try_for_each(|item| consumer(f(item)))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, T> DoubleEndedInternalIterator for Map<I, F>
+where
+    I: DoubleEndedInternalIterator,
+    F: FnMut(I::Item) -> T,
+{
+    fn try_rfor_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut f } = self;
+        iter.try_rfor_each(|item| consumer(f(item)))
+    }
+}
+
+impl<I, F, T> ExactSizeInternalIterator for Map<I, F>
+where
+    I: ExactSizeInternalIterator,
+    F: FnMut(I::Item) -> T,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+
+/// An iterator that maps the values of `iter` with `f`, stopping at the first
+/// `None`.
+#[derive(Clone)]
+pub struct MapWhile<I, F> {
+    pub(crate) iter: I,
+    pub(crate) f: F,
+}
+
+impl<I, F, T> InternalIterator for MapWhile<I, F>
+where
+    I: InternalIterator,
+    F: FnMut(I::Item) -> Option<T>,
+{
+    type Item = T;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut f } = self;
+        let result = iter.try_for_each(|item| match f(item) {
+            Some(mapped) => match consumer(mapped) {
+                ControlFlow::Continue(()) => ControlFlow::Continue(()),
+                ControlFlow::Break(value) => ControlFlow::Break(ControlFlow::Break(value)),
+            },
+            None => ControlFlow::Break(ControlFlow::Continue(())),
+        });
+        match result {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(x) => x,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+
+/// An iterator that iterates over the elements of `iter` in reverse order.
+#[derive(Clone)]
+pub struct Rev<I> {
+    pub(crate) iter: I,
+}
+
+impl<I> InternalIterator for Rev<I>
+where
+    I: DoubleEndedInternalIterator,
+{
+    type Item = I::Item;
+
+    fn try_for_each<R, C>(self, consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        self.iter.try_rfor_each(consumer)
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedInternalIterator for Rev<I>
+where
+    I: DoubleEndedInternalIterator,
+{
+    fn try_rfor_each<R, C>(self, consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        self.iter.try_for_each(consumer)
+    }
+}
+
+impl<I> ExactSizeInternalIterator for Rev<I>
+where
+    I: DoubleEndedInternalIterator + ExactSizeInternalIterator,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+
+/// An iterator to maintain state while iterating another iterator.
+#[derive(Clone)]
+pub struct Scan<I, St, F> {
+    pub(crate) iter: I,
+    pub(crate) state: St,
+    pub(crate) f: F,
+}
+
+impl<I, St, F, T> InternalIterator for Scan<I, St, F>
+where
+    I: InternalIterator,
+    F: FnMut(&mut St, I::Item) -> Option<T>,
+{
+    type Item = T;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut state, mut f } = self;
+        let result = iter.try_for_each(|item| match f(&mut state, item) {
+            Some(mapped) => match consumer(mapped) {
+                ControlFlow::Continue(()) => ControlFlow::Continue(()),
+                ControlFlow::Break(value) => ControlFlow::Break(ControlFlow::Break(value)),
+            },
+            None => ControlFlow::Break(ControlFlow::Continue(())),
+        });
+        match result {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(x) => x,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
 }
 
 
@@ -290,6 +811,126 @@ where
             }
         })
     }
+
+    fn count(self) -> usize {
+        self.iter.count().saturating_sub(self.n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let lower = lower.saturating_sub(self.n);
+        let upper = upper.map(|x| x.saturating_sub(self.n));
+        (lower, upper)
+    }
+}
+
+impl<I> ExactSizeInternalIterator for Skip<I>
+where
+    I: ExactSizeInternalIterator,
+{
+    fn len(&self) -> usize {
+        self.iter.len().saturating_sub(self.n)
+    }
+}
+
+
+/// An iterator that skips elements of `iter` while `predicate` returns `true`.
+#[derive(Clone)]
+pub struct SkipWhile<I, P> {
+    pub(crate) iter: I,
+    pub(crate) predicate: P,
+}
+
+impl<I, P> InternalIterator for SkipWhile<I, P>
+where
+    I: InternalIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut predicate } = self;
+        let mut skipping = true;
+        iter.try_for_each(|item| {
+            if skipping && predicate(&item) {
+                ControlFlow::Continue(())
+            } else {
+                skipping = false;
+                consumer(item)
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+
+/// An iterator that yields every `step`-th element of `iter`, starting with
+/// the first one.
+#[derive(Clone)]
+pub struct StepBy<I> {
+    pub(crate) iter: I,
+    pub(crate) step: usize,
+}
+
+impl<I> InternalIterator for StepBy<I>
+where
+    I: InternalIterator,
+{
+    type Item = I::Item;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, step } = self;
+        let mut skip = 0;
+        iter.try_for_each(|item| {
+            if skip == 0 {
+                skip = step - 1;
+                consumer(item)
+            } else {
+                skip -= 1;
+                ControlFlow::Continue(())
+            }
+        })
+    }
+
+    fn count(self) -> usize {
+        match self.iter.count() {
+            0 => 0,
+            n => (n - 1) / self.step + 1,
+        }
+    }
+
+    fn nth(self, n: usize) -> Option<Self::Item> {
+        match n.checked_mul(self.step) {
+            Some(idx) => self.iter.nth(idx),
+            None => {
+                let mut n = n;
+                self.find_map(|item| {
+                    if n == 0 {
+                        Some(item)
+                    } else {
+                        n -= 1;
+                        None
+                    }
+                })
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let step = |n: usize| if n == 0 { 0 } else { (n - 1) / self.step + 1 };
+        let (lower, upper) = self.iter.size_hint();
+        (step(lower), upper.map(step))
+    }
 }
 
 
@@ -317,7 +958,63 @@ where
         let result = iter.try_for_each(|item| {
             n -= 1;
             match consumer(item) {
-                _ if n == 0 => ControlFlow::Break(ControlFlow::Continue(())),
+                ControlFlow::Break(value) => ControlFlow::Break(ControlFlow::Break(value)),
+                ControlFlow::Continue(()) if n == 0 => ControlFlow::Break(ControlFlow::Continue(())),
+                ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            }
+        });
+        match result {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(x) => x,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let lower = lower.min(self.n);
+        let upper = match upper {
+            Some(x) => x.min(self.n),
+            None => self.n,
+        };
+        (lower, Some(upper))
+    }
+}
+
+impl<I> ExactSizeInternalIterator for Take<I>
+where
+    I: ExactSizeInternalIterator,
+{
+    fn len(&self) -> usize {
+        self.iter.len().min(self.n)
+    }
+}
+
+
+/// An iterator that yields elements of `iter` while `predicate` returns
+/// `true`.
+#[derive(Clone)]
+pub struct TakeWhile<I, P> {
+    pub(crate) iter: I,
+    pub(crate) predicate: P,
+}
+
+impl<I, P> InternalIterator for TakeWhile<I, P>
+where
+    I: InternalIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut predicate } = self;
+        let result = iter.try_for_each(|item| {
+            if !predicate(&item) {
+                return ControlFlow::Break(ControlFlow::Continue(()));
+            }
+            match consumer(item) {
                 ControlFlow::Continue(()) => ControlFlow::Continue(()),
                 ControlFlow::Break(value) => ControlFlow::Break(ControlFlow::Break(value)),
             }
@@ -327,6 +1024,11 @@ where
             ControlFlow::Break(x) => x,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
 }
 
 
@@ -360,4 +1062,29 @@ where
     fn nth(mut self, n: usize) -> Option<Self::Item> {
         self.iterator.nth(n)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<I> DoubleEndedInternalIterator for Internal<I>
+where
+    I: DoubleEndedIterator
+{
+    fn try_rfor_each<T, F>(self, consumer: F) -> ControlFlow<T>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<T>
+    {
+        self.iterator.rev().try_for_each(consumer)
+    }
+}
+
+impl<I> ExactSizeInternalIterator for Internal<I>
+where
+    I: ExactSizeIterator
+{
+    fn len(&self) -> usize {
+        self.iterator.len()
+    }
 }
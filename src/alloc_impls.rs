@@ -1,13 +1,14 @@
 use alloc::{string::String, vec::Vec, collections::{BTreeMap, BTreeSet}};
-use crate::{FromInternalIterator, InternalIterator, IntoInternalIterator, IteratorExt};
+use crate::{FromInternalIterator, InternalExtend, InternalIterator, IntoInternalIterator, IteratorExt};
 
 impl<A> FromInternalIterator<A> for Vec<A> {
     fn from_iter<T>(iter: T) -> Self
     where
         T: IntoInternalIterator<Item = A>
     {
-        let mut result = Vec::new();
-        iter.into_internal_iter().for_each(|item| {
+        let iter = iter.into_internal_iter();
+        let mut result = Vec::with_capacity(iter.size_hint().0);
+        iter.for_each(|item| {
             result.push(item);
         });
         result
@@ -19,8 +20,9 @@ impl FromInternalIterator<char> for String {
     where
         T: IntoInternalIterator<Item = char>
     {
-        let mut result = String::new();
-        iter.into_internal_iter().for_each(|item| {
+        let iter = iter.into_internal_iter();
+        let mut result = String::with_capacity(iter.size_hint().0);
+        iter.for_each(|item| {
             result.push(item);
         });
         result
@@ -53,6 +55,54 @@ impl<K: Ord, V> FromInternalIterator<(K, V)> for BTreeMap<K, V> {
     }
 }
 
+impl<A> InternalExtend<A> for Vec<A> {
+    fn extend_internal_one(&mut self, item: A) {
+        self.push(item);
+    }
+
+    fn extend_internal<T>(&mut self, iter: T)
+    where
+        T: IntoInternalIterator<Item = A>
+    {
+        let iter = iter.into_internal_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|item| self.push(item));
+    }
+}
+
+impl InternalExtend<char> for String {
+    fn extend_internal_one(&mut self, item: char) {
+        self.push(item);
+    }
+
+    fn extend_internal<T>(&mut self, iter: T)
+    where
+        T: IntoInternalIterator<Item = char>
+    {
+        let iter = iter.into_internal_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|item| self.push(item));
+    }
+}
+
+impl<'a> InternalExtend<&'a str> for String {
+    fn extend_internal_one(&mut self, item: &'a str) {
+        self.push_str(item);
+    }
+}
+
+impl<A: Ord> InternalExtend<A> for BTreeSet<A> {
+    fn extend_internal_one(&mut self, item: A) {
+        self.insert(item);
+    }
+}
+
+impl<K: Ord, V> InternalExtend<(K, V)> for BTreeMap<K, V> {
+    fn extend_internal_one(&mut self, (k, v): (K, V)) {
+        self.insert(k, v);
+    }
+}
+
 crate::into_internal_impls! {
     ['a, T] &'a Vec<T>,
     ['a, T] &'a mut Vec<T>,
@@ -0,0 +1,90 @@
+use alloc::boxed::Box;
+use core::ops::ControlFlow;
+use crate::InternalIterator;
+
+/// An object-safe version of [`InternalIterator`].
+///
+/// [`InternalIterator::try_for_each`] is generic, which makes
+/// `dyn InternalIterator` impossible. This trait is implemented for every
+/// internal iterator, and drives it with a `&mut dyn FnMut` instead. The actual
+/// break value is passed back via a side channel, just like in
+/// [`from_fn`](crate::from_fn).
+///
+/// You usually don't need to use this trait directly: create a boxed iterator
+/// with [`InternalIterator::boxed`] and use it as a regular
+/// [`InternalIterator`].
+pub trait DynInternalIterator {
+    /// Type of items yielded by the iterator.
+    type Item;
+
+    /// Applies function to each element of the iterator. Stops early if the
+    /// function returns `ControlFlow::Break`.
+    fn try_for_each_dyn(
+        self: Box<Self>,
+        f: &mut dyn FnMut(Self::Item) -> ControlFlow<()>,
+    ) -> ControlFlow<()>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    fn size_hint_dyn(&self) -> (usize, Option<usize>);
+}
+
+impl<I> DynInternalIterator for I
+where
+    I: InternalIterator,
+{
+    type Item = I::Item;
+
+    fn try_for_each_dyn(
+        self: Box<Self>,
+        f: &mut dyn FnMut(Self::Item) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        (*self).try_for_each(f)
+    }
+
+    fn size_hint_dyn(&self) -> (usize, Option<usize>) {
+        self.size_hint()
+    }
+}
+
+/// A boxed internal iterator, returned by [`InternalIterator::boxed`].
+pub type BoxedInternalIterator<'a, T> = Box<dyn DynInternalIterator<Item = T> + 'a>;
+
+/// A boxed internal iterator that can be sent between threads, returned by
+/// [`InternalIterator::boxed_send`].
+pub type SendBoxedInternalIterator<'a, T> = Box<dyn DynInternalIterator<Item = T> + Send + 'a>;
+
+macro_rules! boxed_impls {
+    ($($ty:ty,)*) => {
+        $(
+            impl<'a, T> InternalIterator for $ty {
+                type Item = T;
+
+                fn try_for_each<R, F>(self, mut f: F) -> ControlFlow<R>
+                where
+                    F: FnMut(Self::Item) -> ControlFlow<R>,
+                {
+                    let mut result = ControlFlow::Continue(());
+                    let _ = self.try_for_each_dyn(&mut |item| {
+                        match f(item) {
+                            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+                            ControlFlow::Break(res) => {
+                                result = ControlFlow::Break(res);
+                                ControlFlow::Break(())
+                            }
+                        }
+                    });
+                    result
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (**self).size_hint_dyn()
+                }
+            }
+        )*
+    };
+}
+
+boxed_impls! {
+    BoxedInternalIterator<'a, T>,
+    SendBoxedInternalIterator<'a, T>,
+}
@@ -7,6 +7,12 @@ pub struct FromFn<F, R> {
     _marker: PhantomData<fn() -> R>,
 }
 
+impl<F: Clone, R> Clone for FromFn<F, R> {
+    fn clone(&self) -> Self {
+        FromFn { f: self.f.clone(), _marker: PhantomData }
+    }
+}
+
 /// A helper type used in [`from_fn`].
 ///
 /// It represents a value that the iterator is stopped with, which `from_fn`
@@ -28,7 +34,7 @@ where
         FF: FnMut(Self::Item) -> ControlFlow<RR>,
     {
         let mut result = ControlFlow::Continue(());
-        (self.f)(&mut |item| {
+        let _ = (self.f)(&mut |item| {
             match f(item) {
                 ControlFlow::Continue(()) => ControlFlow::Continue(()),
                 ControlFlow::Break(res) => {
@@ -82,7 +88,8 @@ where
 /// (to be able to use any type as break value). Because of this, iterators
 /// constructed by [`from_fn`] might be optimized more poorly. If the need
 /// arises such iterators can always be rewritten as explicit structs with
-/// generic implementations of [`InternalIterator::try_for_each`], although that
+/// generic implementations of [`InternalIterator::try_for_each`], which are
+/// compiled separately for every consumer and have no dispatch overhead. That
 /// will require manually handling captured variables (whereas compiler does
 /// that for you when using closures).
 pub fn from_fn<F, R>(f: F) -> FromFn<F, R>
@@ -92,6 +99,196 @@ where
     FromFn { f, _marker: PhantomData }
 }
 
+/// An iterator returned by [`from_fn_repeatable`].
+pub struct FromFnRepeatable<F, R> {
+    f: F,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<F: Clone, R> Clone for FromFnRepeatable<F, R> {
+    fn clone(&self) -> Self {
+        FromFnRepeatable { f: self.f.clone(), _marker: PhantomData }
+    }
+}
+
+impl<F, R> InternalIterator for FromFnRepeatable<F, R>
+where
+    F: for<'a> Fn(&mut dyn FnMut(R) -> ControlFlow<BreakValue<'a>>) -> ControlFlow<BreakValue<'a>>,
+{
+    type Item = R;
+
+    fn try_for_each<RR, FF>(self, f: FF) -> ControlFlow<RR>
+    where
+        FF: FnMut(Self::Item) -> ControlFlow<RR>,
+    {
+        (&self).try_for_each(f)
+    }
+}
+
+impl<F, R> InternalIterator for &FromFnRepeatable<F, R>
+where
+    F: for<'a> Fn(&mut dyn FnMut(R) -> ControlFlow<BreakValue<'a>>) -> ControlFlow<BreakValue<'a>>,
+{
+    type Item = R;
+
+    fn try_for_each<RR, FF>(self, mut f: FF) -> ControlFlow<RR>
+    where
+        FF: FnMut(Self::Item) -> ControlFlow<RR>,
+    {
+        let mut result = ControlFlow::Continue(());
+        let _ = (self.f)(&mut |item| {
+            match f(item) {
+                ControlFlow::Continue(()) => ControlFlow::Continue(()),
+                ControlFlow::Break(res) => {
+                    result = ControlFlow::Break(res);
+                    ControlFlow::Break(BreakValue { _invariant: PhantomData })
+                }
+            }
+        });
+        result
+    }
+}
+
+/// Creates an internal iterator from provided closure, which can be iterated
+/// over multiple times.
+///
+/// This works like [`from_fn`], but takes an [`Fn`] closure instead of an
+/// [`FnOnce`]. The returned iterator can be iterated over by reference any
+/// number of times, and it is [`Clone`] whenever the closure is, so it can be
+/// used with adaptors such as [`InternalIterator::cycle`].
+///
+/// ```
+/// # use internal_iterator::InternalIterator;
+/// # use std::ops::ControlFlow;
+/// let values = vec![1, 2, 3];
+///
+/// let iter = internal_iterator::from_fn_repeatable(move |f| {
+///     for &value in &values {
+///         f(value)?;
+///     }
+///     ControlFlow::Continue(())
+/// });
+///
+/// assert_eq!((&iter).collect::<Vec<_>>(), [1, 2, 3]);
+/// assert_eq!((&iter).map(|x| x * 2).collect::<Vec<_>>(), [2, 4, 6]);
+/// assert_eq!(iter.cycle().take(5).collect::<Vec<_>>(), [1, 2, 3, 1, 2]);
+/// ```
+pub fn from_fn_repeatable<F, R>(f: F) -> FromFnRepeatable<F, R>
+where
+    F: for<'a> Fn(&mut dyn FnMut(R) -> ControlFlow<BreakValue<'a>>) -> ControlFlow<BreakValue<'a>>,
+{
+    FromFnRepeatable { f, _marker: PhantomData }
+}
+
+/// An iterator returned by [`from_fn_rec`].
+pub struct FromFnRec<N, F, R> {
+    root: N,
+    f: F,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<N: Clone, F: Clone, R> Clone for FromFnRec<N, F, R> {
+    fn clone(&self) -> Self {
+        FromFnRec { root: self.root.clone(), f: self.f.clone(), _marker: PhantomData }
+    }
+}
+
+type RecFn<'h, 'a, N, R> = dyn for<'x> Fn(N, &mut Recurse<'x, 'a, N, R>) -> ControlFlow<BreakValue<'a>> + 'h;
+
+/// A handle given to [`from_fn_rec`] closures, used to yield values and to
+/// recurse into child nodes.
+pub struct Recurse<'h, 'a, N, R> {
+    f: &'h RecFn<'h, 'a, N, R>,
+    yield_: &'h mut dyn FnMut(R) -> ControlFlow<BreakValue<'a>>,
+}
+
+impl<'a, N, R> Recurse<'_, 'a, N, R> {
+    /// Runs the closure on a child node. The result must be propagated with
+    /// `?`.
+    pub fn recurse(&mut self, node: N) -> ControlFlow<BreakValue<'a>> {
+        let f = self.f;
+        f(node, self)
+    }
+
+    /// Yields a single value. The result must be propagated with `?`.
+    pub fn yield_(&mut self, item: R) -> ControlFlow<BreakValue<'a>> {
+        (self.yield_)(item)
+    }
+}
+
+impl<N, F, R> InternalIterator for FromFnRec<N, F, R>
+where
+    F: for<'h, 'a> Fn(N, &mut Recurse<'h, 'a, N, R>) -> ControlFlow<BreakValue<'a>>,
+{
+    type Item = R;
+
+    fn try_for_each<RR, FF>(self, mut f: FF) -> ControlFlow<RR>
+    where
+        FF: FnMut(Self::Item) -> ControlFlow<RR>,
+    {
+        let mut result = ControlFlow::Continue(());
+        let mut consumer = |item| {
+            match f(item) {
+                ControlFlow::Continue(()) => ControlFlow::Continue(()),
+                ControlFlow::Break(res) => {
+                    result = ControlFlow::Break(res);
+                    ControlFlow::Break(BreakValue { _invariant: PhantomData })
+                }
+            }
+        };
+        let mut handle = Recurse { f: &self.f, yield_: &mut consumer };
+        let _ = handle.recurse(self.root);
+        result
+    }
+}
+
+/// Creates an internal iterator from a recursive closure.
+///
+/// The closure is first called with `root`. It receives a [`Recurse`] handle,
+/// which can yield values with [`Recurse::yield_`] and run the same closure on
+/// a child node with [`Recurse::recurse`]. This allows writing tree walks
+/// inline, without a separate helper function. Just like with [`from_fn`],
+/// every call on the handle must be followed by `?` so that iteration stops
+/// when the consumer breaks.
+///
+/// ```
+/// # use internal_iterator::InternalIterator;
+/// # use std::ops::ControlFlow;
+/// struct Tree {
+///     value: i32,
+///     children: Vec<Tree>,
+/// }
+///
+/// let tree = Tree {
+///     value: 1,
+///     children: vec![
+///         Tree { value: 2, children: vec![] },
+///         Tree { value: 3, children: vec![Tree { value: 4, children: vec![] }] },
+///     ],
+/// };
+///
+/// let iter = internal_iterator::from_fn_rec(&tree, |node: &Tree, r| {
+///     r.yield_(node.value)?;
+///     for child in &node.children {
+///         r.recurse(child)?;
+///     }
+///     ControlFlow::Continue(())
+/// });
+///
+/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4]);
+/// ```
+///
+/// The closure is an [`Fn`], because it can be running several times at once
+/// (once for each level of recursion). Use [`Cell`](core::cell::Cell) or
+/// [`RefCell`](core::cell::RefCell) if it needs to update captured state.
+/// Recursion uses the call stack, so very deep structures can overflow it.
+pub fn from_fn_rec<N, F, R>(root: N, f: F) -> FromFnRec<N, F, R>
+where
+    F: for<'h, 'a> Fn(N, &mut Recurse<'h, 'a, N, R>) -> ControlFlow<BreakValue<'a>>,
+{
+    FromFnRec { root, f, _marker: PhantomData }
+}
+
 #[test]
 fn pipeline_tests() {
     fn check(iter: impl InternalIterator<Item = i32>, expect: &[i32]) {
@@ -130,6 +327,103 @@ fn can_be_static() {
     use_static_iterator(iter);
 }
 
+#[test]
+fn repeatable_and_clone() {
+    fn check<I: InternalIterator<Item = i32> + Clone>(iter: I) {
+        assert_eq!(iter.clone().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(iter.cycle().take(4).collect::<Vec<_>>(), [1, 2, 3, 1]);
+    }
+
+    let values = vec![1, 2, 3];
+    check(from_fn(|f| {
+        for &value in &values {
+            f(value)?;
+        }
+        ControlFlow::Continue(())
+    }));
+
+    // closure that owns a non-Clone value can still be iterated by reference
+    struct NotClone(Vec<i32>);
+    let owned = NotClone(values.clone());
+    let repeatable = from_fn_repeatable(move |f| {
+        for &value in &owned.0 {
+            f(value)?;
+        }
+        ControlFlow::Continue(())
+    });
+    check(&repeatable);
+    assert_eq!((&repeatable).find(|&x| x == 2), Some(2));
+    assert_eq!(repeatable.count(), 3);
+}
+
+#[test]
+fn recursive_early_exit() {
+    struct Tree(i32, Vec<Tree>);
+
+    let tree = Tree(1, vec![
+        Tree(2, vec![Tree(3, vec![])]),
+        Tree(4, vec![Tree(5, vec![]), Tree(6, vec![])]),
+    ]);
+
+    let visited = core::cell::RefCell::new(Vec::new());
+    let found = from_fn_rec(&tree, |node: &Tree, r| {
+        visited.borrow_mut().push(node.0);
+        r.yield_(node.0)?;
+        for child in &node.1 {
+            r.recurse(child)?;
+        }
+        ControlFlow::Continue(())
+    })
+    .find(|&x| x == 4);
+    assert_eq!(found, Some(4));
+    assert_eq!(visited.into_inner(), [1, 2, 3, 4]);
+
+    let countdown = from_fn_rec(5, |n: i32, r| {
+        if n > 0 {
+            r.yield_(n)?;
+            r.recurse(n - 1)?;
+        }
+        ControlFlow::Continue(())
+    });
+    assert_eq!(countdown.clone().sum::<i32>(), 15);
+    assert_eq!(countdown.take(2).collect::<Vec<_>>(), [5, 4]);
+}
+
+#[test]
+fn from_fn_matches_hand_written() {
+    struct Squares(u64);
+
+    impl InternalIterator for Squares {
+        type Item = u64;
+
+        fn try_for_each<R, F>(self, mut f: F) -> ControlFlow<R>
+        where
+            F: FnMut(u64) -> ControlFlow<R>,
+        {
+            for i in 0..self.0 {
+                f(i * i)?;
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn squares(n: u64) -> impl InternalIterator<Item = u64> {
+        from_fn(move |f| {
+            for i in 0..n {
+                f(i * i)?;
+            }
+            ControlFlow::Continue(())
+        })
+    }
+
+    fn pipeline(iter: impl InternalIterator<Item = u64>) -> u64 {
+        iter.filter(|x| x % 3 == 0).map(|x| x / 3).sum()
+    }
+
+    assert_eq!(pipeline(squares(1000)), pipeline(Squares(1000)));
+    assert_eq!(squares(1000).find(|&x| x > 50), Squares(1000).find(|&x| x > 50));
+}
+
 /// ```compile_fail
 /// use std::ops::ControlFlow;
 /// use internal_iterator::InternalIterator;
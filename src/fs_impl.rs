@@ -0,0 +1,202 @@
+use core::ops::ControlFlow;
+use std::fs::{self, DirEntry};
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::InternalIterator;
+
+/// An iterator returned by [`walk_dir`].
+#[derive(Clone)]
+pub struct WalkDir<P = fn(&DirEntry) -> bool> {
+    root: PathBuf,
+    max_depth: usize,
+    follow_links: bool,
+    sort: bool,
+    prune: P,
+}
+
+/// An entry found by [`walk_dir`].
+#[derive(Debug)]
+pub struct WalkDirEntry {
+    /// The directory entry.
+    pub entry: DirEntry,
+    /// Number of directories between the root and this entry. Entries directly
+    /// inside the root have depth 1.
+    pub depth: usize,
+}
+
+impl<P> WalkDir<P> {
+    /// Only yields entries up to the given depth. Entries directly inside the
+    /// root have depth 1, so `max_depth(1)` does not descend into any
+    /// subdirectories.
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        WalkDir { max_depth, ..self }
+    }
+
+    /// Descends into symbolic links that point to directories. Disabled by
+    /// default.
+    ///
+    /// A link that points to one of its own ancestors is reported as an error
+    /// instead of being followed.
+    pub fn follow_links(self, follow_links: bool) -> Self {
+        WalkDir { follow_links, ..self }
+    }
+
+    /// Yields the entries of every directory sorted by file name. By default
+    /// they are yielded in the order returned by [`fs::read_dir`], which is
+    /// platform dependent.
+    pub fn sort_by_file_name(self) -> Self {
+        WalkDir { sort: true, ..self }
+    }
+
+    /// Does not descend into directories for which `predicate` returns
+    /// `true`. Such directories are still yielded themselves.
+    pub fn prune<P2>(self, predicate: P2) -> WalkDir<P2>
+    where
+        P2: FnMut(&DirEntry) -> bool,
+    {
+        let WalkDir { root, max_depth, follow_links, sort, prune: _ } = self;
+        WalkDir { root, max_depth, follow_links, sort, prune: predicate }
+    }
+}
+
+impl<P> WalkDir<P>
+where
+    P: FnMut(&DirEntry) -> bool,
+{
+    /// Runs the closure on every entry, stopping at the first I/O error or
+    /// when the closure returns `ControlFlow::Break`.
+    ///
+    /// An error from reading a directory or inspecting an entry ends the walk
+    /// with `ControlFlow::Break(R::from(err))`, instead of being skipped.
+    pub fn try_for_each_entry<R, F>(self, mut f: F) -> ControlFlow<R>
+    where
+        R: From<io::Error>,
+        F: FnMut(WalkDirEntry) -> ControlFlow<R>,
+    {
+        self.try_for_each(|entry| match entry {
+            Ok(entry) => f(entry),
+            Err(err) => ControlFlow::Break(R::from(err)),
+        })
+    }
+
+    fn read_dir(&self, path: &Path, depth: usize) -> io::Result<Vec<(io::Result<DirEntry>, usize)>> {
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| (entry, depth))
+            .collect::<Vec<_>>();
+        if self.sort {
+            entries.sort_by_cached_key(|(entry, _)| entry.as_ref().ok().map(DirEntry::file_name));
+        }
+        Ok(entries)
+    }
+
+    fn is_dir(&self, entry: &DirEntry) -> io::Result<bool> {
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() && self.follow_links {
+            Ok(fs::metadata(entry.path())?.is_dir())
+        } else {
+            Ok(file_type.is_dir())
+        }
+    }
+}
+
+impl<P> InternalIterator for WalkDir<P>
+where
+    P: FnMut(&DirEntry) -> bool,
+{
+    type Item = io::Result<WalkDirEntry>;
+
+    fn try_for_each<R, F>(mut self, mut consumer: F) -> ControlFlow<R>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        // canonical paths of the directories containing the current entry,
+        // used to detect symlink loops
+        let mut ancestors = Vec::new();
+        if self.follow_links {
+            match fs::canonicalize(&self.root) {
+                Ok(root) => ancestors.push(root),
+                Err(err) => return consumer(Err(err)),
+            }
+        }
+        if self.max_depth == 0 {
+            return ControlFlow::Continue(());
+        }
+        let mut stack = match self.read_dir(&self.root, 1) {
+            Ok(entries) => entries,
+            Err(err) => return consumer(Err(err)),
+        };
+        stack.reverse();
+        while let Some((entry, depth)) = stack.pop() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    consumer(Err(err))?;
+                    continue;
+                }
+            };
+            let descend = depth < self.max_depth && match self.is_dir(&entry) {
+                Ok(is_dir) => is_dir && !(self.prune)(&entry),
+                Err(err) => {
+                    consumer(Err(err))?;
+                    false
+                }
+            };
+            let path = entry.path();
+            consumer(Ok(WalkDirEntry { entry, depth }))?;
+            if !descend {
+                continue;
+            }
+            if self.follow_links {
+                ancestors.truncate(depth);
+                let canonical = match fs::canonicalize(&path) {
+                    Ok(canonical) => canonical,
+                    Err(err) => {
+                        consumer(Err(err))?;
+                        continue;
+                    }
+                };
+                if ancestors.contains(&canonical) {
+                    let message = format!("filesystem loop found at {}", path.display());
+                    consumer(Err(io::Error::other(message)))?;
+                    continue;
+                }
+                ancestors.push(canonical);
+            }
+            match self.read_dir(&path, depth + 1) {
+                Ok(entries) => stack.extend(entries.into_iter().rev()),
+                Err(err) => consumer(Err(err))?,
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Creates an iterator that recursively walks the directory at `root`.
+///
+/// Every entry below `root` is yielded together with its depth, and every
+/// directory is yielded before its contents. The root itself is not yielded.
+/// Like [`walk`](crate::walk), it does not recurse.
+///
+/// I/O errors are yielded as `Err` items, and the walk continues with the
+/// remaining entries. Use [`WalkDir::try_for_each_entry`] to stop at the first
+/// error instead.
+///
+/// ```no_run
+/// # use internal_iterator::InternalIterator;
+/// let sources = internal_iterator::walk_dir("src")
+///     .sort_by_file_name()
+///     .prune(|entry| entry.file_name() == "target")
+///     .filter_map(|entry| entry.ok())
+///     .filter(|e| e.entry.path().extension().is_some_and(|ext| ext == "rs"))
+///     .map(|e| e.entry.path())
+///     .collect::<Vec<_>>();
+/// ```
+pub fn walk_dir(root: impl AsRef<Path>) -> WalkDir {
+    WalkDir {
+        root: root.as_ref().to_path_buf(),
+        max_depth: usize::MAX,
+        follow_links: false,
+        sort: false,
+        prune: |_| false,
+    }
+}
@@ -0,0 +1,486 @@
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+use crate::{InternalIterator, IntoInternalIterator};
+
+/// A set used by graph traversals to remember visited nodes.
+///
+/// Implemented for [`BTreeSet`], and for [`HashSet`](std::collections::HashSet)
+/// when the `std` feature is enabled.
+pub trait VisitedSet<N> {
+    /// Returns `true` if the node was already visited.
+    fn contains(&self, node: &N) -> bool;
+
+    /// Marks the node as visited. Returns `true` if it was not visited before.
+    fn insert(&mut self, node: N) -> bool;
+}
+
+impl<N: Ord> VisitedSet<N> for BTreeSet<N> {
+    fn contains(&self, node: &N) -> bool {
+        BTreeSet::contains(self, node)
+    }
+
+    fn insert(&mut self, node: N) -> bool {
+        BTreeSet::insert(self, node)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<N, H> VisitedSet<N> for std::collections::HashSet<N, H>
+where
+    N: Eq + core::hash::Hash,
+    H: core::hash::BuildHasher,
+{
+    fn contains(&self, node: &N) -> bool {
+        std::collections::HashSet::contains(self, node)
+    }
+
+    fn insert(&mut self, node: N) -> bool {
+        std::collections::HashSet::insert(self, node)
+    }
+}
+
+/// A node reached during a graph traversal, yielded by [`Dfs::visits`] and
+/// [`Bfs::visits`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Visit<N> {
+    /// The visited node.
+    pub node: N,
+    /// Number of edges between the start node and this node along the path
+    /// taken by the traversal.
+    pub depth: usize,
+    /// The node from which this node was reached, or `None` for the start
+    /// node.
+    pub parent: Option<N>,
+}
+
+/// An iterator returned by [`dfs`].
+#[derive(Clone)]
+pub struct Dfs<N, F, S> {
+    start: N,
+    neighbors: F,
+    visited: S,
+}
+
+/// An iterator returned by [`bfs`].
+#[derive(Clone)]
+pub struct Bfs<N, F, S> {
+    start: N,
+    neighbors: F,
+    visited: S,
+}
+
+/// An iterator returned by [`Dfs::visits`] and [`Bfs::visits`].
+#[derive(Clone)]
+pub struct Visits<G> {
+    traversal: G,
+}
+
+macro_rules! traversal_methods {
+    ($ty:ident) => {
+        impl<N, F, S> $ty<N, F, S> {
+            /// Uses the provided set to track visited nodes. Nodes that are
+            /// already in the set are not visited.
+            pub fn with_visited<S2>(self, visited: S2) -> $ty<N, F, S2>
+            where
+                S2: VisitedSet<N>,
+            {
+                $ty { start: self.start, neighbors: self.neighbors, visited }
+            }
+
+            /// Creates an iterator that yields a [`Visit`] for every node,
+            /// which includes its depth and parent.
+            pub fn visits(self) -> Visits<Self> {
+                Visits { traversal: self }
+            }
+        }
+
+        impl<N, F, S, C> InternalIterator for $ty<N, F, S>
+        where
+            N: Clone,
+            F: FnMut(&N) -> C,
+            C: IntoInternalIterator<Item = N>,
+            S: VisitedSet<N>,
+        {
+            type Item = N;
+
+            fn try_for_each<R, G>(self, mut consumer: G) -> ControlFlow<R>
+            where
+                G: FnMut(Self::Item) -> ControlFlow<R>
+            {
+                self.try_for_each_visit(|visit| consumer(visit.node))
+            }
+        }
+
+        impl<N, F, S, C> InternalIterator for Visits<$ty<N, F, S>>
+        where
+            N: Clone,
+            F: FnMut(&N) -> C,
+            C: IntoInternalIterator<Item = N>,
+            S: VisitedSet<N>,
+        {
+            type Item = Visit<N>;
+
+            fn try_for_each<R, G>(self, consumer: G) -> ControlFlow<R>
+            where
+                G: FnMut(Self::Item) -> ControlFlow<R>
+            {
+                self.traversal.try_for_each_visit(consumer)
+            }
+        }
+    };
+}
+
+traversal_methods!(Dfs);
+traversal_methods!(Bfs);
+
+impl<N, F, S, C> Dfs<N, F, S>
+where
+    N: Clone,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+    S: VisitedSet<N>,
+{
+    fn try_for_each_visit<R, G>(self, mut consumer: G) -> ControlFlow<R>
+    where
+        G: FnMut(Visit<N>) -> ControlFlow<R>,
+    {
+        let Self { start, mut neighbors, mut visited } = self;
+        if visited.contains(&start) {
+            return ControlFlow::Continue(());
+        }
+        let mut stack = Vec::new();
+        stack.push(Visit { node: start, depth: 0, parent: None });
+        while let Some(visit) = stack.pop() {
+            // a node can be pushed several times before it is visited
+            if !visited.insert(visit.node.clone()) {
+                continue;
+            }
+            let parent = visit.node.clone();
+            let depth = visit.depth + 1;
+            consumer(visit)?;
+            let first_neighbor = stack.len();
+            neighbors(&parent).into_internal_iter().for_each(|node| {
+                if !visited.contains(&node) {
+                    stack.push(Visit { node, depth, parent: Some(parent.clone()) });
+                }
+            });
+            stack[first_neighbor..].reverse();
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl<N, F, S, C> Bfs<N, F, S>
+where
+    N: Clone,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+    S: VisitedSet<N>,
+{
+    fn try_for_each_visit<R, G>(self, mut consumer: G) -> ControlFlow<R>
+    where
+        G: FnMut(Visit<N>) -> ControlFlow<R>,
+    {
+        let Self { start, mut neighbors, mut visited } = self;
+        if !visited.insert(start.clone()) {
+            return ControlFlow::Continue(());
+        }
+        let mut queue = VecDeque::new();
+        queue.push_back(Visit { node: start, depth: 0, parent: None });
+        while let Some(visit) = queue.pop_front() {
+            let parent = visit.node.clone();
+            let depth = visit.depth + 1;
+            consumer(visit)?;
+            neighbors(&parent).into_internal_iter().for_each(|node| {
+                if visited.insert(node.clone()) {
+                    queue.push_back(Visit { node, depth, parent: Some(parent.clone()) });
+                }
+            });
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Creates an iterator that walks a graph in depth-first order, starting at
+/// `start`.
+///
+/// The `neighbors` closure is called on every visited node and returns the
+/// nodes reachable from it. Every node is yielded at most once, which makes
+/// this suitable for graphs with shared nodes or cycles. Visited nodes are
+/// tracked in a [`BTreeSet`] by default, which requires `N: Ord`. Use
+/// [`Dfs::with_visited`] to use a different set, such as a `HashSet` for
+/// nodes that only implement `Hash` and `Eq`. Like [`walk`](crate::walk), it
+/// does not recurse.
+///
+/// ```
+/// # use internal_iterator::InternalIterator;
+/// // edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 2 -> 0
+/// let edges = [vec![1, 2], vec![3], vec![3, 0], vec![]];
+///
+/// let order = internal_iterator::dfs(0, |&n: &usize| edges[n].clone())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(order, [0, 1, 3, 2]);
+/// ```
+pub fn dfs<N, F, C>(start: N, neighbors: F) -> Dfs<N, F, BTreeSet<N>>
+where
+    N: Clone,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    Dfs { start, neighbors, visited: BTreeSet::new() }
+}
+
+/// Creates an iterator that walks a graph in breadth-first order, starting at
+/// `start`.
+///
+/// This works like [`dfs`], but visits nodes in order of their distance from
+/// `start`.
+///
+/// ```
+/// # use internal_iterator::InternalIterator;
+/// # use std::collections::HashSet;
+/// let edges = [vec![1, 2], vec![3], vec![3, 0], vec![]];
+///
+/// let visits = internal_iterator::bfs(0, |&n: &usize| edges[n].clone())
+///     .with_visited(HashSet::new())
+///     .visits()
+///     .map(|v| (v.node, v.depth, v.parent))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(visits, [(0, 0, None), (1, 1, Some(0)), (2, 1, Some(0)), (3, 2, Some(1))]);
+/// ```
+pub fn bfs<N, F, C>(start: N, neighbors: F) -> Bfs<N, F, BTreeSet<N>>
+where
+    N: Clone,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    Bfs { start, neighbors, visited: BTreeSet::new() }
+}
+
+/// An error yielded by [`topo_order`] when the graph contains a cycle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleError<N> {
+    /// Nodes forming the cycle, in order: every node depends on the next one,
+    /// and the last node depends on the first one.
+    pub cycle: Vec<N>,
+}
+
+/// An iterator returned by [`topo_order`].
+#[derive(Clone)]
+pub struct TopoOrder<I, F> {
+    nodes: I,
+    edges: F,
+}
+
+impl<I, F, N, C> InternalIterator for TopoOrder<I, F>
+where
+    I: IntoInternalIterator<Item = N>,
+    N: Clone + Ord,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    type Item = Result<N, CycleError<N>>;
+
+    fn try_for_each<R, G>(self, mut consumer: G) -> ControlFlow<R>
+    where
+        G: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { nodes, mut edges } = self;
+        // `false` while the node is on the stack, `true` once it is yielded
+        let mut done = BTreeMap::new();
+        let mut stack: Vec<(N, alloc::vec::IntoIter<N>)> = Vec::new();
+        let result = nodes.into_internal_iter().try_for_each(|root| {
+            if done.contains_key(&root) {
+                return ControlFlow::Continue(());
+            }
+            done.insert(root.clone(), false);
+            let dependencies = edges(&root).into_internal_iter().collect::<Vec<_>>();
+            stack.push((root, dependencies.into_iter()));
+            while let Some((_, dependencies)) = stack.last_mut() {
+                match dependencies.next() {
+                    Some(dependency) => match done.get(&dependency) {
+                        Some(true) => {}
+                        Some(false) => {
+                            let start = stack.iter().position(|(n, _)| *n == dependency).unwrap();
+                            let cycle = stack.drain(start..).map(|(n, _)| n).collect();
+                            consumer(Err(CycleError { cycle })).map_break(Some)?;
+                            return ControlFlow::Break(None);
+                        }
+                        None => {
+                            done.insert(dependency.clone(), false);
+                            let dependencies = edges(&dependency).into_internal_iter().collect::<Vec<_>>();
+                            stack.push((dependency, dependencies.into_iter()));
+                        }
+                    },
+                    None => {
+                        let (node, _) = stack.pop().unwrap();
+                        done.insert(node.clone(), true);
+                        consumer(Ok(node)).map_break(Some)?;
+                    }
+                }
+            }
+            ControlFlow::Continue(())
+        });
+        match result {
+            ControlFlow::Break(Some(value)) => ControlFlow::Break(value),
+            ControlFlow::Break(None) | ControlFlow::Continue(()) => ControlFlow::Continue(()),
+        }
+    }
+}
+
+/// Creates an iterator that yields graph nodes in dependency order.
+///
+/// The `edges` closure returns the dependencies of a node. Every node reachable
+/// from `nodes` is yielded exactly once, after all of its dependencies. If the
+/// graph contains a cycle, a [`CycleError`] is yielded and the iteration
+/// stops, so collecting into a `Result` gives either the full order or the
+/// cycle.
+///
+/// ```
+/// # use internal_iterator::{CycleError, InternalIterator};
+/// // "app" depends on "lib" and "log", "lib" depends on "log"
+/// let deps = |&n: &&str| match n {
+///     "app" => vec!["lib", "log"],
+///     "lib" => vec!["log"],
+///     _ => vec![],
+/// };
+///
+/// let order = internal_iterator::topo_order(["app"], deps).collect::<Result<Vec<_>, _>>();
+/// assert_eq!(order, Ok(vec!["log", "lib", "app"]));
+///
+/// let cyclic = |&n: &&str| match n {
+///     "a" => vec!["b"],
+///     "b" => vec!["a"],
+///     _ => vec![],
+/// };
+///
+/// let order = internal_iterator::topo_order(["a"], cyclic).collect::<Result<Vec<_>, _>>();
+/// assert_eq!(order, Err(CycleError { cycle: vec!["a", "b"] }));
+/// ```
+pub fn topo_order<I, F, N, C>(nodes: I, edges: F) -> TopoOrder<I, F>
+where
+    I: IntoInternalIterator<Item = N>,
+    N: Clone + Ord,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    TopoOrder { nodes, edges }
+}
+
+/// An iterator returned by [`strongly_connected_components`].
+#[derive(Clone)]
+pub struct StronglyConnectedComponents<I, F> {
+    nodes: I,
+    edges: F,
+}
+
+struct TarjanState {
+    index: usize,
+    lowlink: usize,
+    on_stack: bool,
+}
+
+impl<I, F, N, C> InternalIterator for StronglyConnectedComponents<I, F>
+where
+    I: IntoInternalIterator<Item = N>,
+    N: Clone + Ord,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    type Item = Vec<N>;
+
+    fn try_for_each<R, G>(self, mut consumer: G) -> ControlFlow<R>
+    where
+        G: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { nodes, mut edges } = self;
+        let mut states = BTreeMap::new();
+        let mut next_index = 0;
+        let mut component_stack = Vec::new();
+        let mut stack: Vec<(N, alloc::vec::IntoIter<N>)> = Vec::new();
+        let mut enter = |
+            node: N,
+            states: &mut BTreeMap<N, TarjanState>,
+            stack: &mut Vec<_>,
+            component_stack: &mut Vec<N>,
+        | {
+            let state = TarjanState { index: next_index, lowlink: next_index, on_stack: true };
+            states.insert(node.clone(), state);
+            next_index += 1;
+            component_stack.push(node.clone());
+            let successors = edges(&node).into_internal_iter().collect::<Vec<_>>();
+            stack.push((node, successors.into_iter()));
+        };
+        nodes.into_internal_iter().try_for_each(|root| {
+            if states.contains_key(&root) {
+                return ControlFlow::Continue(());
+            }
+            enter(root, &mut states, &mut stack, &mut component_stack);
+            while let Some((node, successors)) = stack.last_mut() {
+                if let Some(successor) = successors.next() {
+                    match states.get(&successor) {
+                        None => enter(successor, &mut states, &mut stack, &mut component_stack),
+                        Some(state) if state.on_stack => {
+                            let index = state.index;
+                            let state = states.get_mut(node).unwrap();
+                            state.lowlink = state.lowlink.min(index);
+                        }
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+                let (node, _) = stack.pop().unwrap();
+                let state = &states[&node];
+                let (index, lowlink) = (state.index, state.lowlink);
+                if let Some((parent, _)) = stack.last() {
+                    let parent = states.get_mut(parent).unwrap();
+                    parent.lowlink = parent.lowlink.min(lowlink);
+                }
+                if index == lowlink {
+                    let start = component_stack.iter().rposition(|n| *n == node).unwrap();
+                    let component = component_stack.split_off(start);
+                    for member in &component {
+                        states.get_mut(member).unwrap().on_stack = false;
+                    }
+                    consumer(component)?;
+                }
+            }
+            ControlFlow::Continue(())
+        })
+    }
+}
+
+/// Creates an iterator that yields strongly connected components of a graph.
+///
+/// The `edges` closure returns the successors of a node. Components reachable
+/// from `nodes` are found with Tarjan's algorithm, and every component is
+/// yielded after all components reachable from it. If edges point from nodes
+/// to their dependencies, this is dependency order, just like in
+/// [`topo_order`].
+///
+/// ```
+/// # use internal_iterator::InternalIterator;
+/// // 0 -> 1 -> 2 -> 0 form a cycle, 2 -> 3
+/// let edges = [vec![1], vec![2], vec![0, 3], vec![]];
+///
+/// let components = internal_iterator::strongly_connected_components([0], |&n: &usize| edges[n].clone())
+///     .map(|mut c| { c.sort(); c })
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(components, [vec![3], vec![0, 1, 2]]);
+/// ```
+pub fn strongly_connected_components<I, F, N, C>(
+    nodes: I,
+    edges: F,
+) -> StronglyConnectedComponents<I, F>
+where
+    I: IntoInternalIterator<Item = N>,
+    N: Clone + Ord,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    StronglyConnectedComponents { nodes, edges }
+}
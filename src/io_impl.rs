@@ -0,0 +1,237 @@
+use core::ops::ControlFlow;
+use std::io::{self, BufRead};
+use crate::{LendingInternalIterator, LendingItem, LendingItemOf};
+
+/// A lending iterator returned by [`read_lines`].
+#[derive(Clone)]
+pub struct ReadLines<B> {
+    reader: B,
+}
+
+impl<B> ReadLines<B>
+where
+    B: BufRead,
+{
+    /// Runs the closure on every line, stopping at the first I/O error or
+    /// when the closure returns `ControlFlow::Break`.
+    ///
+    /// A read error, or a line that is not valid UTF-8, is returned as
+    /// `ControlFlow::Break(R::from(err))`. Below, a line that fails to parse is
+    /// reported as an `io::Error` too.
+    ///
+    /// ```
+    /// # use std::io;
+    /// # use std::ops::ControlFlow;
+    /// let input = io::Cursor::new("1\n2\nthree\n4\n");
+    ///
+    /// let mut sum = 0;
+    /// let result = internal_iterator::read_lines(input).try_for_each_line(|line| {
+    ///     match line.parse::<i32>() {
+    ///         Ok(x) => {
+    ///             sum += x;
+    ///             ControlFlow::Continue(())
+    ///         }
+    ///         Err(_) => ControlFlow::Break(io::Error::new(io::ErrorKind::InvalidData, line)),
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(sum, 3);
+    /// assert!(matches!(result, ControlFlow::Break(err) if err.to_string() == "three"));
+    /// ```
+    pub fn try_for_each_line<R, F>(self, mut f: F) -> ControlFlow<R>
+    where
+        R: From<io::Error>,
+        F: FnMut(&str) -> ControlFlow<R>,
+    {
+        self.try_for_each(|line| match line {
+            Ok(line) => f(line),
+            Err(err) => ControlFlow::Break(R::from(err)),
+        })
+    }
+}
+
+impl<'a, B> LendingItem<'a> for ReadLines<B>
+where
+    B: BufRead,
+{
+    type Item = io::Result<&'a str>;
+}
+
+impl<B> LendingInternalIterator for ReadLines<B>
+where
+    B: BufRead,
+{
+    fn try_for_each<R, F>(self, mut f: F) -> ControlFlow<R>
+    where
+        F: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>
+    {
+        let result = for_each_record(self.reader, b'\n', |record| {
+            let line = record.and_then(|(mut line, terminated)| {
+                // like `BufRead::lines`, only strip `\r` that comes before `\n`
+                if let (true, [rest @ .., b'\r']) = (terminated, line) {
+                    line = rest;
+                }
+                core::str::from_utf8(line)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+            });
+            match line {
+                Ok(line) => f(Ok(line)).map_break(ControlFlow::Break),
+                // an invalid line ends the iteration just like a read error
+                Err(err) => ControlFlow::Break(f(Err(err))),
+            }
+        });
+        match result {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(x) => x,
+        }
+    }
+}
+
+/// A lending iterator returned by [`read_split`].
+#[derive(Clone)]
+pub struct ReadSplit<B> {
+    reader: B,
+    delimiter: u8,
+}
+
+impl<B> ReadSplit<B>
+where
+    B: BufRead,
+{
+    /// Runs the closure on every record, stopping at the first I/O error or
+    /// when the closure returns `ControlFlow::Break`.
+    ///
+    /// A read error is returned as `ControlFlow::Break(R::from(err))`, and the
+    /// record being read when it happened is not passed to the closure.
+    ///
+    /// ```
+    /// # use std::io;
+    /// # use std::ops::ControlFlow;
+    /// let input = io::Cursor::new(b"a\0bc\0\0def");
+    ///
+    /// let mut lengths = Vec::new();
+    /// let result = internal_iterator::read_split(input, 0).try_for_each_record(|record| {
+    ///     lengths.push(record.len());
+    ///     ControlFlow::<io::Error>::Continue(())
+    /// });
+    ///
+    /// assert!(result.is_continue());
+    /// assert_eq!(lengths, [1, 2, 0, 3]);
+    /// ```
+    pub fn try_for_each_record<R, F>(self, mut f: F) -> ControlFlow<R>
+    where
+        R: From<io::Error>,
+        F: FnMut(&[u8]) -> ControlFlow<R>,
+    {
+        self.try_for_each(|record| match record {
+            Ok(record) => f(record),
+            Err(err) => ControlFlow::Break(R::from(err)),
+        })
+    }
+}
+
+impl<'a, B> LendingItem<'a> for ReadSplit<B>
+where
+    B: BufRead,
+{
+    type Item = io::Result<&'a [u8]>;
+}
+
+impl<B> LendingInternalIterator for ReadSplit<B>
+where
+    B: BufRead,
+{
+    fn try_for_each<R, F>(self, mut f: F) -> ControlFlow<R>
+    where
+        F: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>
+    {
+        let Self { reader, delimiter } = self;
+        for_each_record(reader, delimiter, |record| f(record.map(|(record, _)| record)))
+    }
+}
+
+/// Reads `delimiter`-terminated records into a single reused buffer, along
+/// with whether the record ended with the delimiter. An error is passed to `f`
+/// and ends the iteration.
+fn for_each_record<B, R, F>(mut reader: B, delimiter: u8, mut f: F) -> ControlFlow<R>
+where
+    B: BufRead,
+    F: FnMut(io::Result<(&[u8], bool)>) -> ControlFlow<R>,
+{
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_until(delimiter, &mut buf) {
+            Ok(0) => return ControlFlow::Continue(()),
+            Ok(_) => {
+                let record = match buf.split_last() {
+                    Some((&last, rest)) if last == delimiter => (rest, true),
+                    _ => (&buf[..], false),
+                };
+                f(Ok(record))?;
+            }
+            Err(err) => {
+                f(Err(err))?;
+                return ControlFlow::Continue(());
+            }
+        }
+    }
+}
+
+/// Creates a lending iterator over the lines of `reader`.
+///
+/// Lines are split on `\n`, and a `\r` right before the `\n` is removed as
+/// well, just like in [`BufRead::lines`]. Instead of allocating a `String` for
+/// every line, one buffer is reused and each line is lent out as a `&str`.
+///
+/// An I/O error, or a line that is not valid UTF-8, is yielded as an `Err`
+/// item and ends the iteration. Use [`ReadLines::try_for_each_line`] to get
+/// errors as the break value instead.
+///
+/// ```
+/// # use internal_iterator::{InternalIterator, LendingInternalIterator};
+/// # use std::io;
+/// let input = io::Cursor::new("INFO start\nWARN disk\r\nINFO done\nWARN cpu\n");
+///
+/// let warnings = internal_iterator::read_lines(input)
+///     .filter(|line| matches!(line, Ok(l) if l.starts_with("WARN")))
+///     .map(|line| line.map(|l| l[5..].to_string()))
+///     .collect::<io::Result<Vec<_>>>()
+///     .unwrap();
+///
+/// assert_eq!(warnings, ["disk", "cpu"]);
+/// ```
+pub fn read_lines<B>(reader: B) -> ReadLines<B>
+where
+    B: BufRead,
+{
+    ReadLines { reader }
+}
+
+/// Creates a lending iterator over the records of `reader` separated by the
+/// `delimiter` byte.
+///
+/// The delimiter is not included in the records. One buffer is reused for
+/// every record, and each record is lent out as a `&[u8]`.
+///
+/// An I/O error is yielded as an `Err` item and ends the iteration. Use
+/// [`ReadSplit::try_for_each_record`] to get errors as the break value instead.
+///
+/// ```
+/// # use internal_iterator::{InternalIterator, LendingInternalIterator};
+/// # use std::io;
+/// let input = io::Cursor::new(b"x=1;y=22;z=333");
+///
+/// let values = internal_iterator::read_split(input, b';')
+///     .map(|record| record.map(|r| r[2..].to_vec()))
+///     .collect::<io::Result<Vec<_>>>()
+///     .unwrap();
+///
+/// assert_eq!(values, [&b"1"[..], b"22", b"333"]);
+/// ```
+pub fn read_split<B>(reader: B, delimiter: u8) -> ReadSplit<B>
+where
+    B: BufRead,
+{
+    ReadSplit { reader, delimiter }
+}
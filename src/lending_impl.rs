@@ -0,0 +1,419 @@
+use core::ops::ControlFlow;
+use crate::InternalIterator;
+
+/// Defines the type of items that a [`LendingInternalIterator`] lends out for
+/// the lifetime `'a`.
+///
+/// The `ImplicitBound` parameter should always be left at its default. It
+/// makes `Self: 'a` an implied bound of every impl, so items can borrow both
+/// from the iterator and from whatever the iterator itself borrows.
+///
+/// ```
+/// # use internal_iterator::{LendingInternalIterator, LendingItem, LendingItemOf};
+/// # use std::ops::ControlFlow;
+/// struct Pairs<'s>(&'s mut [i32]);
+///
+/// impl<'a> LendingItem<'a> for Pairs<'_> {
+///     type Item = &'a mut [i32];
+/// }
+///
+/// impl LendingInternalIterator for Pairs<'_> {
+///     fn try_for_each<R, F>(self, mut f: F) -> ControlFlow<R>
+///     where
+///         F: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>,
+///     {
+///         for pair in self.0.chunks_mut(2) {
+///             f(pair)?;
+///         }
+///         ControlFlow::Continue(())
+///     }
+/// }
+///
+/// let mut values = [1, 2, 3, 4, 5];
+/// Pairs(&mut values).for_each(|pair| pair.reverse());
+/// assert_eq!(values, [2, 1, 4, 3, 5]);
+/// ```
+pub trait LendingItem<'a, ImplicitBound = &'a Self> {
+    /// Type of items lent out for the lifetime `'a`.
+    type Item;
+}
+
+/// Type of items that the lending iterator `I` lends out for the lifetime
+/// `'a`.
+pub type LendingItemOf<'a, I> = <I as LendingItem<'a>>::Item;
+
+/// Internal iterator whose items may borrow from the iterator itself.
+///
+/// [`InternalIterator::Item`] is a single type, so every item has to outlive
+/// the whole iteration. Here the item type, defined with [`LendingItem`], is
+/// generic over a lifetime, and an item only has to live for the duration of
+/// one consumer call. This allows handing out `&mut` references into a buffer
+/// that is reused for every item.
+///
+/// Because items cannot outlive the consumer call, only adaptors that use
+/// items in place are available. [`map`](LendingInternalIterator::map) turns
+/// items into owned values, and the result is a regular [`InternalIterator`].
+///
+/// ```
+/// # use internal_iterator::{InternalIterator, IntoInternalIterator, LendingInternalIterator};
+/// let words = ["hello", "lending", "world"];
+///
+/// let shouted = words
+///     .into_internal_iter()
+///     .with_scratch(String::new(), |buf, word| {
+///         buf.clear();
+///         buf.push_str(word);
+///         buf.make_ascii_uppercase();
+///     })
+///     .filter(|buf| buf.len() == 5)
+///     .map(|buf| buf.clone())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(shouted, ["HELLO", "WORLD"]);
+/// ```
+#[must_use = "internal iterators are lazy and do nothing unless consumed"]
+pub trait LendingInternalIterator: Sized + for<'a> LendingItem<'a> {
+    /// Applies function to each element of the iterator. Stops early if the
+    /// function returns `ControlFlow::Break`.
+    fn try_for_each<R, F>(self, f: F) -> ControlFlow<R>
+    where
+        F: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>;
+
+    /// Creates an iterator that adds the index to every value of the original
+    /// iterator.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator, LendingInternalIterator};
+    /// let indexed = [3, 1, 2]
+    ///     .into_internal_iter()
+    ///     .with_scratch(0, |buf, x| *buf = x)
+    ///     .enumerate()
+    ///     .map(|(i, buf)| i * 10 + *buf)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(indexed, [3, 11, 22]);
+    /// ```
+    fn enumerate(self) -> LendingEnumerate<Self> {
+        LendingEnumerate { iter: self }
+    }
+
+    /// Creates an iterator which only yields elements matching the predicate.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator, LendingInternalIterator};
+    /// let odd = [1, 2, 3, 4, 5]
+    ///     .into_internal_iter()
+    ///     .with_scratch(0, |buf, x| *buf = x)
+    ///     .filter(|buf| **buf % 2 == 1)
+    ///     .map(|buf| *buf)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// ```
+    fn filter<P>(self, predicate: P) -> LendingFilter<Self, P>
+    where
+        P: for<'a> FnMut(&LendingItemOf<'a, Self>) -> bool,
+    {
+        LendingFilter { iter: self, predicate }
+    }
+
+    /// Applies function to the elements of iterator and returns the first
+    /// non-none result.
+    ///
+    /// ```
+    /// # use internal_iterator::{IntoInternalIterator, InternalIterator, LendingInternalIterator};
+    /// let found = ["a", "bb", "ccc"]
+    ///     .into_internal_iter()
+    ///     .with_scratch(String::new(), |buf, s| {
+    ///         buf.clear();
+    ///         buf.push_str(s);
+    ///     })
+    ///     .find_map(|buf| (buf.len() > 1).then(|| buf.clone()));
+    ///
+    /// assert_eq!(found.as_deref(), Some("bb"));
+    /// ```
+    fn find_map<T, F>(self, mut f: F) -> Option<T>
+    where
+        F: for<'a> FnMut(LendingItemOf<'a, Self>) -> Option<T>,
+    {
+        let value = self.try_for_each(|item| match f(item) {
+            Some(value) => ControlFlow::Break(value),
+            None => ControlFlow::Continue(()),
+        });
+        match value {
+            ControlFlow::Continue(()) => None,
+            ControlFlow::Break(value) => Some(value),
+        }
+    }
+
+    /// Run the closure on each element.
+    fn for_each<F>(self, mut f: F)
+    where
+        F: for<'a> FnMut(LendingItemOf<'a, Self>),
+    {
+        let _ = self.try_for_each::<(), _>(|item| {
+            f(item);
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// Transform each element into an owned value. The result is a regular
+    /// [`InternalIterator`].
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator, LendingInternalIterator};
+    /// let lengths = ["a", "bb", "ccc"]
+    ///     .into_internal_iter()
+    ///     .with_scratch(String::new(), |buf, s| {
+    ///         buf.clear();
+    ///         buf.push_str(s);
+    ///     })
+    ///     .map(|buf| buf.len())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(lengths, [1, 2, 3]);
+    /// ```
+    fn map<F, T>(self, f: F) -> LendingMap<Self, F>
+    where
+        F: for<'a> FnMut(LendingItemOf<'a, Self>) -> T,
+    {
+        LendingMap { iter: self, f }
+    }
+
+    /// Skip first `n` elements of the iterator.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator, LendingInternalIterator};
+    /// let rest = [1, 2, 3, 4]
+    ///     .into_internal_iter()
+    ///     .with_scratch(0, |buf, x| *buf = x)
+    ///     .skip(2)
+    ///     .map(|buf| *buf)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(rest, [3, 4]);
+    /// ```
+    fn skip(self, n: usize) -> LendingSkip<Self> {
+        LendingSkip { iter: self, n }
+    }
+
+    /// Take first `n` elements of the iterator, disregarding the rest.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator, LendingInternalIterator};
+    /// let first = [1, 2, 3, 4]
+    ///     .into_internal_iter()
+    ///     .with_scratch(0, |buf, x| *buf = x)
+    ///     .take(2)
+    ///     .map(|buf| *buf)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(first, [1, 2]);
+    /// ```
+    fn take(self, n: usize) -> LendingTake<Self> {
+        LendingTake { iter: self, n }
+    }
+}
+
+
+/// A lending iterator that yields the current count and the element during
+/// iteration.
+#[derive(Clone)]
+pub struct LendingEnumerate<I> {
+    pub(crate) iter: I,
+}
+
+impl<'a, I> LendingItem<'a> for LendingEnumerate<I>
+where
+    I: LendingInternalIterator,
+{
+    type Item = (usize, LendingItemOf<'a, I>);
+}
+
+impl<I> LendingInternalIterator for LendingEnumerate<I>
+where
+    I: LendingInternalIterator,
+{
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>
+    {
+        let mut idx = 0;
+        self.iter.try_for_each(|item| {
+            let next = idx + 1;
+            let idx = core::mem::replace(&mut idx, next);
+            consumer((idx, item))
+        })
+    }
+}
+
+
+/// A lending iterator that filters the elements of `iter` with `predicate`.
+#[derive(Clone)]
+pub struct LendingFilter<I, F> {
+    pub(crate) iter: I,
+    pub(crate) predicate: F,
+}
+
+impl<'a, I, F> LendingItem<'a> for LendingFilter<I, F>
+where
+    I: LendingInternalIterator,
+{
+    type Item = LendingItemOf<'a, I>;
+}
+
+impl<I, F> LendingInternalIterator for LendingFilter<I, F>
+where
+    I: LendingInternalIterator,
+    F: for<'a> FnMut(&LendingItemOf<'a, I>) -> bool,
+{
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>
+    {
+        let Self { iter, mut predicate } = self;
+        iter.try_for_each(|item| {
+            if predicate(&item) {
+                consumer(item)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+    }
+}
+
+
+/// An iterator that maps the borrowed elements of `iter` with `f` into owned
+/// values.
+#[derive(Clone)]
+pub struct LendingMap<I, F> {
+    pub(crate) iter: I,
+    pub(crate) f: F,
+}
+
+impl<I, F, T> InternalIterator for LendingMap<I, F>
+where
+    I: LendingInternalIterator,
+    F: for<'a> FnMut(LendingItemOf<'a, I>) -> T,
+{
+    type Item = T;
+
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { iter, mut f } = self;
+        iter.try_for_each(|item| consumer(f(item)))
+    }
+}
+
+
+/// A lending iterator that skips over `n` elements of `iter`.
+#[derive(Clone)]
+pub struct LendingSkip<I> {
+    pub(crate) iter: I,
+    pub(crate) n: usize,
+}
+
+impl<'a, I> LendingItem<'a> for LendingSkip<I>
+where
+    I: LendingInternalIterator,
+{
+    type Item = LendingItemOf<'a, I>;
+}
+
+impl<I> LendingInternalIterator for LendingSkip<I>
+where
+    I: LendingInternalIterator,
+{
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>
+    {
+        let Self { iter, mut n } = self;
+        iter.try_for_each(|item| {
+            if n == 0 {
+                consumer(item)
+            } else {
+                n -= 1;
+                ControlFlow::Continue(())
+            }
+        })
+    }
+}
+
+
+/// A lending iterator that only iterates over the first `n` elements of
+/// `iter`.
+#[derive(Clone)]
+pub struct LendingTake<I> {
+    pub(crate) iter: I,
+    pub(crate) n: usize,
+}
+
+impl<'a, I> LendingItem<'a> for LendingTake<I>
+where
+    I: LendingInternalIterator,
+{
+    type Item = LendingItemOf<'a, I>;
+}
+
+impl<I> LendingInternalIterator for LendingTake<I>
+where
+    I: LendingInternalIterator,
+{
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>
+    {
+        let Self { iter, mut n } = self;
+        if n == 0 {
+            return ControlFlow::Continue(());
+        }
+        let result = iter.try_for_each(|item| {
+            n -= 1;
+            match consumer(item) {
+                ControlFlow::Break(value) => ControlFlow::Break(ControlFlow::Break(value)),
+                ControlFlow::Continue(()) if n == 0 => ControlFlow::Break(ControlFlow::Continue(())),
+                ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            }
+        });
+        match result {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(x) => x,
+        }
+    }
+}
+
+
+/// A lending iterator returned by [`InternalIterator::with_scratch`].
+#[derive(Clone)]
+pub struct WithScratch<I, S, F> {
+    pub(crate) iter: I,
+    pub(crate) scratch: S,
+    pub(crate) fill: F,
+}
+
+impl<'a, I, S, F> LendingItem<'a> for WithScratch<I, S, F>
+where
+    I: InternalIterator,
+{
+    type Item = &'a mut S;
+}
+
+impl<I, S, F> LendingInternalIterator for WithScratch<I, S, F>
+where
+    I: InternalIterator,
+    F: FnMut(&mut S, I::Item),
+{
+    fn try_for_each<R, C>(self, mut consumer: C) -> ControlFlow<R>
+    where
+        C: for<'a> FnMut(LendingItemOf<'a, Self>) -> ControlFlow<R>
+    {
+        let Self { iter, mut scratch, mut fill } = self;
+        iter.try_for_each(|item| {
+            fill(&mut scratch, item);
+            consumer(&mut scratch)
+        })
+    }
+}
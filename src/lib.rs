@@ -101,10 +101,15 @@ most prominent example is [`Iterator::zip`].
 This crate has two optional features:
 
 * `alloc` - includes `FromInternalIterator` and `IntoInternalIterator` impls
-for `String`, `Vec`, `BTreeMap`, and `BTreeSet`. Brings in a dependency on
+for `String`, `Vec`, `BTreeMap`, and `BTreeSet`, boxed iterators created
+with `InternalIterator::boxed`, the `walk` tree traversal, and graph
+traversals such as `dfs`, `bfs`, and `topo_order`. Brings in a dependency on
 `alloc`.
 * `std` - includes `FromInternalIterator` and `IntoInternalIterator` impls for
-`HashSet` and `HashMap`. Brings in a dependency on `std`.
+`HashSet` and `HashMap`, allows using `HashSet` to track visited nodes in
+graph traversals, adds `read_lines` and `read_split` for reading from
+`BufRead` without allocating per line, and adds the `walk_dir` directory
+walker. Brings in a dependency on `std`.
 
 Both of these features are enabled by default, but you can disable them if you
 are compiling without `std` or even without `alloc`."]
@@ -116,12 +121,25 @@ are compiling without `std` or even without `alloc`."]
 
 mod adaptors;
 mod from_fn_impl;
+mod lending_impl;
+mod sum_impl;
+mod try_impl;
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "alloc")]
 mod alloc_impls;
+#[cfg(feature = "alloc")]
+mod dyn_impl;
+#[cfg(feature = "alloc")]
+mod graph_impl;
+#[cfg(feature = "alloc")]
+mod walk_impl;
 
+#[cfg(feature = "std")]
+mod fs_impl;
+#[cfg(feature = "std")]
+mod io_impl;
 #[cfg(feature = "std")]
 mod std_impls;
 
@@ -131,7 +149,29 @@ mod tests;
 use core::cmp::Ordering;
 use core::ops::ControlFlow;
 pub use crate::adaptors::*;
-pub use crate::from_fn_impl::{FromFn, BreakValue, from_fn};
+#[cfg(feature = "alloc")]
+pub use crate::dyn_impl::{DynInternalIterator, BoxedInternalIterator, SendBoxedInternalIterator};
+#[cfg(feature = "alloc")]
+pub use crate::graph_impl::{
+    Bfs, CycleError, Dfs, StronglyConnectedComponents, TopoOrder, Visit, VisitedSet, Visits,
+    bfs, dfs, strongly_connected_components, topo_order,
+};
+#[cfg(feature = "alloc")]
+pub use crate::walk_impl::{PrunedWalk, Walk, WalkControl, walk};
+pub use crate::from_fn_impl::{
+    FromFn, FromFnRec, FromFnRepeatable, BreakValue, Recurse, from_fn, from_fn_rec,
+    from_fn_repeatable,
+};
+#[cfg(feature = "std")]
+pub use crate::fs_impl::{WalkDir, WalkDirEntry, walk_dir};
+#[cfg(feature = "std")]
+pub use crate::io_impl::{ReadLines, ReadSplit, read_lines, read_split};
+pub use crate::lending_impl::{
+    LendingEnumerate, LendingFilter, LendingInternalIterator, LendingItem, LendingItemOf, LendingMap,
+    LendingSkip, LendingTake, WithScratch,
+};
+pub use crate::sum_impl::{InternalSum, InternalProduct};
+pub use crate::try_impl::{Try, Residual};
 
 /// Internal iterator over a collection.
 #[must_use = "internal iterators are lazy and do nothing unless consumed"]
@@ -225,6 +265,44 @@ pub trait InternalIterator: Sized {
         self.find_map(|item| if f(item) { Some(()) } else { None }).is_some()
     }
 
+    /// Boxes the iterator, erasing its type.
+    ///
+    /// This allows storing iterators of different types together, or returning
+    /// them from trait methods. The returned box implements
+    /// [`InternalIterator`] itself.
+    ///
+    /// ```
+    /// # use internal_iterator::{BoxedInternalIterator, InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3];
+    ///
+    /// let iters: Vec<BoxedInternalIterator<'_, i32>> = vec![
+    ///     a.iter().into_internal().copied().boxed(),
+    ///     a.iter().into_internal().map(|x| x * 10).boxed(),
+    /// ];
+    ///
+    /// let v = iters.into_iter().into_internal().flatten().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v, vec![1, 2, 3, 10, 20, 30]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed<'a>(self) -> BoxedInternalIterator<'a, Self::Item>
+    where
+        Self: 'a,
+    {
+        alloc::boxed::Box::new(self)
+    }
+
+    /// Boxes the iterator, erasing its type, while keeping it [`Send`].
+    ///
+    /// See [`InternalIterator::boxed`].
+    #[cfg(feature = "alloc")]
+    fn boxed_send<'a>(self) -> SendBoxedInternalIterator<'a, Self::Item>
+    where
+        Self: Send + 'a,
+    {
+        alloc::boxed::Box::new(self)
+    }
+
     /// Takes two iterators and returns an iterator that first iterates over the
     /// elements of the first iterator, and then over the second one.
     ///
@@ -256,10 +334,10 @@ pub trait InternalIterator: Sized {
     ///
     /// assert_eq!(cloned, vec![1, 2, 3]);
     /// ```
-    fn cloned<'a, T: 'a>(self) -> Cloned<Self>
+    fn cloned<'a, T>(self) -> Cloned<Self>
     where
         Self: InternalIterator<Item = &'a T>,
-        T: Clone,
+        T: Clone + 'a,
     {
         Cloned { iter: self }
     }
@@ -295,10 +373,10 @@ pub trait InternalIterator: Sized {
     ///
     /// assert_eq!(cloned, vec![1, 2, 3]);
     /// ```
-    fn copied<'a, T: 'a>(self) -> Copied<Self>
+    fn copied<'a, T>(self) -> Copied<Self>
     where
         Self: InternalIterator<Item = &'a T>,
-        T: Copy,
+        T: Copy + 'a,
     {
         Copied { iter: self }
     }
@@ -317,7 +395,25 @@ pub trait InternalIterator: Sized {
         count
     }
 
-    // TODO: cycle
+    /// Creates an iterator that repeats the original iterator endlessly.
+    ///
+    /// Each round of iteration is driven by a fresh clone of the original
+    /// iterator. If the original iterator is empty, so is the resulting one.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3];
+    ///
+    /// let v = a.iter().into_internal().cycle().take(7).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v, vec![&1, &2, &3, &1, &2, &3, &1]);
+    /// ```
+    fn cycle(self) -> Cycle<Self>
+    where
+        Self: Clone,
+    {
+        Cycle { iter: self }
+    }
 
     /// Creates an iterator that adds the index to every value of the original
     /// iterator.
@@ -423,7 +519,25 @@ pub trait InternalIterator: Sized {
         FlatMap { iter: self, f }
     }
 
-    // TODO: flatten
+    /// Creates an iterator that flattens nested structure.
+    ///
+    /// Items of the original iterator are expected to implement
+    /// [`IntoInternalIterator`]. This is equivalent to `.flat_map(|x| x)`.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator};
+    /// let a = [vec![1, 2], vec![], vec![3]];
+    ///
+    /// let flattened = a.into_internal_iter().flatten().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(flattened, vec![1, 2, 3]);
+    /// ```
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self::Item: IntoInternalIterator,
+    {
+        Flatten { iter: self }
+    }
 
     /// Folds every element into an accumulator by applying an operation, returning the final result.
     /// 
@@ -500,6 +614,32 @@ pub trait InternalIterator: Sized {
         last
     }
 
+    /// Wraps the iterator in [`Either::Left`].
+    ///
+    /// This allows returning different iterator types from different branches
+    /// without boxing.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// fn evens_or_all(a: &[i32], only_even: bool) -> impl InternalIterator<Item = &i32> {
+    ///     if only_even {
+    ///         a.iter().into_internal().filter(|&&x| x % 2 == 0).left_internal()
+    ///     } else {
+    ///         a.iter().into_internal().right_internal()
+    ///     }
+    /// }
+    ///
+    /// let a = [1, 2, 3, 4];
+    /// assert_eq!(evens_or_all(&a, true).collect::<Vec<_>>(), vec![&2, &4]);
+    /// assert_eq!(evens_or_all(&a, false).collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    fn left_internal<R>(self) -> Either<Self, R>
+    where
+        R: InternalIterator<Item = Self::Item>,
+    {
+        Either::Left(self)
+    }
+
     /// Transform each element in the iterator.
     ///
     /// ```
@@ -521,6 +661,29 @@ pub trait InternalIterator: Sized {
         Map { iter: self, f }
     }
 
+    /// Creates an iterator that both yields elements based on a predicate and
+    /// maps. Iteration stops at the first element for which the closure
+    /// returns `None`.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = ["1", "2", "NaN", "4"];
+    ///
+    /// let parsed = a
+    ///     .iter()
+    ///     .into_internal()
+    ///     .map_while(|x| x.parse::<i32>().ok())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(parsed, vec![1, 2]);
+    /// ```
+    fn map_while<F, T>(self, f: F) -> MapWhile<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<T>,
+    {
+        MapWhile { iter: self, f }
+    }
+
     /// Returns the maximum element of an iterator.
     ///
     /// ```
@@ -638,6 +801,39 @@ pub trait InternalIterator: Sized {
         })
     }
 
+    /// Splits the elements of the iterator into two collections. Elements for
+    /// which the predicate returns `true` go to the first collection, and the
+    /// rest go to the second one.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    ///
+    /// let (even, odd): (Vec<&i32>, Vec<&i32>) = a
+    ///     .iter()
+    ///     .into_internal()
+    ///     .partition(|&x| x % 2 == 0);
+    ///
+    /// assert_eq!(even, vec![&2, &4]);
+    /// assert_eq!(odd, vec![&1, &3, &5]);
+    /// ```
+    fn partition<B, F>(self, mut f: F) -> (B, B)
+    where
+        B: Default + InternalExtend<Self::Item>,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        let mut left = B::default();
+        let mut right = B::default();
+        self.for_each(|item| {
+            if f(&item) {
+                left.extend_internal_one(item);
+            } else {
+                right.extend_internal_one(item);
+            }
+        });
+        (left, right)
+    }
+
     /// Returns the index of the first element matching the predicate.
     ///
     /// ```
@@ -661,9 +857,104 @@ pub trait InternalIterator: Sized {
         })
     }
 
-    // TODO: product
+    /// Multiplies all elements of the iterator.
+    ///
+    /// Just like with [`Iterator::product`], iterators over `Option<T>` and
+    /// `Result<T, E>` can be multiplied, stopping at the first `None` or
+    /// `Err`.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 4];
+    /// assert_eq!(a.iter().into_internal().product::<i32>(), 24);
+    ///
+    /// let b = [Some(2), None, Some(3)];
+    /// assert_eq!(b.iter().into_internal().copied().product::<Option<i32>>(), None);
+    /// ```
+    fn product<P>(self) -> P
+    where
+        P: InternalProduct<Self::Item>,
+    {
+        P::product(self)
+    }
+
+    /// Reduces the elements to a single one by repeatedly applying the
+    /// reducing operation. Returns `None` if the iterator is empty.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 4];
+    /// let b: [i32; 0] = [];
+    ///
+    /// assert_eq!(a.iter().into_internal().copied().reduce(|acc, x| acc * x), Some(24));
+    /// assert_eq!(b.iter().into_internal().copied().reduce(|acc, x| acc * x), None);
+    /// ```
+    fn reduce<F>(self, mut f: F) -> Option<Self::Item>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        self.fold(None, |acc, item| match acc {
+            None => Some(item),
+            Some(acc) => Some(f(acc, item)),
+        })
+    }
 
-    // TODO: scan
+    /// Wraps the iterator in [`Either::Right`].
+    ///
+    /// See [`InternalIterator::left_internal`].
+    fn right_internal<L>(self) -> Either<L, Self>
+    where
+        L: InternalIterator<Item = Self::Item>,
+    {
+        Either::Right(self)
+    }
+
+    /// Creates an iterator that carries mutable state between elements.
+    ///
+    /// The closure receives a mutable reference to the state and an element,
+    /// and returns the element to yield. Iteration stops at the first `None`
+    /// returned from the closure.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 4];
+    ///
+    /// let running_totals = a
+    ///     .iter()
+    ///     .into_internal()
+    ///     .scan(0, |total, &x| {
+    ///         *total += x;
+    ///         if *total > 6 { None } else { Some(*total) }
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(running_totals, vec![1, 3, 6]);
+    /// ```
+    fn scan<St, F, T>(self, initial_state: St, f: F) -> Scan<Self, St, F>
+    where
+        F: FnMut(&mut St, Self::Item) -> Option<T>,
+    {
+        Scan { iter: self, state: initial_state, f }
+    }
+
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// This works just like [`Iterator::size_hint`]: it returns a lower bound
+    /// and an optional upper bound on the number of elements the iterator will
+    /// yield. The default implementation returns `(0, None)`, which is correct
+    /// for any iterator. Collections use the lower bound to reserve space up
+    /// front.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 4];
+    ///
+    /// assert_eq!(a.iter().into_internal().size_hint(), (4, Some(4)));
+    /// assert_eq!(a.iter().into_internal().filter(|&&x| x > 2).size_hint(), (0, Some(4)));
+    /// ```
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
 
     /// Skip first `n` elements of the iterator.
     ///
@@ -679,11 +970,64 @@ pub trait InternalIterator: Sized {
         Skip { iter: self, n }
     }
 
-    // TODO: skip_while
+    /// Creates an iterator that skips elements while the predicate returns
+    /// `true`, and yields every element after that.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [-1i32, 0, 1, -2];
+    ///
+    /// let v = a.iter().into_internal().skip_while(|x| x.is_negative()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v, vec![&0, &1, &-2]);
+    /// ```
+    fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        SkipWhile { iter: self, predicate }
+    }
 
-    // TODO: step_by
+    /// Creates an iterator that yields the first element and then every
+    /// `step`-th element after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [0, 1, 2, 3, 4, 5];
+    ///
+    /// let v = a.iter().into_internal().step_by(2).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v, vec![&0, &2, &4]);
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self> {
+        assert!(step != 0, "step_by requires a non-zero step");
+        StepBy { iter: self, step }
+    }
 
-    // TODO: sum
+    /// Sums all elements of the iterator.
+    ///
+    /// Just like with [`Iterator::sum`], iterators over `Option<T>` and
+    /// `Result<T, E>` can be summed, stopping at the first `None` or `Err`.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3];
+    /// assert_eq!(a.iter().into_internal().sum::<i32>(), 6);
+    ///
+    /// let b = ["1", "2", "three"];
+    /// let sum = b.iter().into_internal().map(|x| x.parse::<i32>()).sum::<Result<i32, _>>();
+    /// assert!(sum.is_err());
+    /// ```
+    fn sum<S>(self) -> S
+    where
+        S: InternalSum<Self::Item>,
+    {
+        S::sum(self)
+    }
 
     /// Take first `n` elements of the iterator, disregarding the rest.
     ///
@@ -699,13 +1043,399 @@ pub trait InternalIterator: Sized {
         Take { iter: self, n }
     }
 
-    // TODO: take_while
+    /// Creates an iterator that yields elements while the predicate returns
+    /// `true`. The underlying iterator is stopped at the first element that
+    /// does not match the predicate.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [-1i32, 0, 1, -2];
+    ///
+    /// let v = a.iter().into_internal().take_while(|x| x.is_negative()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v, vec![&-1]);
+    /// ```
+    fn take_while<P>(self, predicate: P) -> TakeWhile<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhile { iter: self, predicate }
+    }
+
+    /// Returns the first element of the iterator that matches a fallible
+    /// predicate. Stops at the first failure of the predicate and returns
+    /// that failure.
+    ///
+    /// The predicate can return [`Option<bool>`], [`Result<bool, E>`], or
+    /// [`ControlFlow<B, bool>`]; see [`Try`].
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = ["1", "2", "lol", "NaN", "5"];
+    ///
+    /// let is_my_num = |s: &&str| -> Result<bool, std::num::ParseIntError> {
+    ///     Ok(s.parse::<i32>()? == 2)
+    /// };
+    ///
+    /// let result = a.iter().into_internal().copied().try_find(is_my_num);
+    /// assert_eq!(result, Ok(Some("2")));
+    ///
+    /// let result = a.iter().into_internal().copied().filter(|&s| s != "2").try_find(is_my_num);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_find<F, R>(self, mut f: F) -> <R::Residual as Residual<Option<Self::Item>>>::TryType
+    where
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Option<Self::Item>>,
+    {
+        let result = self.try_for_each(|item| match f(&item).branch() {
+            ControlFlow::Continue(false) => ControlFlow::Continue(()),
+            ControlFlow::Continue(true) => ControlFlow::Break(Ok(item)),
+            ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
+        });
+        match result {
+            ControlFlow::Continue(()) => Try::from_output(None),
+            ControlFlow::Break(Ok(item)) => Try::from_output(Some(item)),
+            ControlFlow::Break(Err(residual)) => Try::from_residual(residual),
+        }
+    }
+
+    /// Folds every element into an accumulator by applying a fallible
+    /// operation. Stops at the first failure and returns it.
+    ///
+    /// The closure can return [`Option`], [`Result`], or [`ControlFlow`]; see
+    /// [`Try`].
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [1u8, 2, 3];
+    /// let sum = a.iter().into_internal().try_fold(0u8, |acc, &x| acc.checked_add(x));
+    /// assert_eq!(sum, Some(6));
+    ///
+    /// let a = [100u8, 100, 100];
+    /// let sum = a.iter().into_internal().try_fold(0u8, |acc, &x| acc.checked_add(x));
+    /// assert_eq!(sum, None);
+    /// ```
+    fn try_fold<B, F, R>(self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        let mut acc = Some(init);
+        let result = self.try_for_each(|item| {
+            let current = acc.take().unwrap();
+            match f(current, item).branch() {
+                ControlFlow::Continue(next) => {
+                    acc = Some(next);
+                    ControlFlow::Continue(())
+                }
+                ControlFlow::Break(residual) => ControlFlow::Break(residual),
+            }
+        });
+        match result {
+            ControlFlow::Continue(()) => R::from_output(acc.unwrap()),
+            ControlFlow::Break(residual) => R::from_residual(residual),
+        }
+    }
+
+    /// Reduces the elements to a single one by repeatedly applying a fallible
+    /// reducing operation. Stops at the first failure and returns it.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [10u8, 20, 5];
+    /// let sum = a.iter().into_internal().copied().try_reduce(|x, y| x.checked_add(y));
+    /// assert_eq!(sum, Some(Some(35)));
+    ///
+    /// let a = [100u8, 100, 100];
+    /// let sum = a.iter().into_internal().copied().try_reduce(|x, y| x.checked_add(y));
+    /// assert_eq!(sum, None);
+    /// ```
+    fn try_reduce<F, R>(self, mut f: F) -> <R::Residual as Residual<Option<Self::Item>>>::TryType
+    where
+        F: FnMut(Self::Item, Self::Item) -> R,
+        R: Try<Output = Self::Item>,
+        R::Residual: Residual<Option<Self::Item>>,
+    {
+        let mut acc = None;
+        let result = self.try_for_each(|item| {
+            let next = match acc.take() {
+                None => item,
+                Some(current) => match f(current, item).branch() {
+                    ControlFlow::Continue(next) => next,
+                    ControlFlow::Break(residual) => return ControlFlow::Break(residual),
+                },
+            };
+            acc = Some(next);
+            ControlFlow::Continue(())
+        });
+        match result {
+            ControlFlow::Continue(()) => Try::from_output(acc),
+            ControlFlow::Break(residual) => Try::from_residual(residual),
+        }
+    }
 
-    // TODO: try_find
+    /// Converts an iterator of pairs into a pair of collections.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt};
+    /// let a = [(1, 'a'), (2, 'b'), (3, 'c')];
+    ///
+    /// let (numbers, letters): (Vec<_>, String) = a.iter().into_internal().copied().unzip();
+    ///
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// assert_eq!(letters, "abc");
+    /// ```
+    fn unzip<A, B, FromA, FromB>(self) -> (FromA, FromB)
+    where
+        Self: InternalIterator<Item = (A, B)>,
+        FromA: Default + InternalExtend<A>,
+        FromB: Default + InternalExtend<B>,
+    {
+        let mut result = (FromA::default(), FromB::default());
+        result.extend_internal(self);
+        result
+    }
 
-    // TODO: try_fold
+    /// Creates a [`LendingInternalIterator`] that writes every element into a
+    /// single `scratch` value using `fill`, and lends out a mutable reference
+    /// to it.
+    ///
+    /// This allows reusing one buffer for all elements instead of allocating
+    /// a new one each time.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IteratorExt, LendingInternalIterator};
+    /// use std::fmt::Write;
+    ///
+    /// let a = [7, 42, 100];
+    ///
+    /// let mut rendered = Vec::new();
+    /// a.iter()
+    ///     .into_internal()
+    ///     .with_scratch(String::new(), |buf, x| {
+    ///         buf.clear();
+    ///         write!(buf, "{}", x).unwrap();
+    ///     })
+    ///     .for_each(|buf| rendered.push(buf.len()));
+    ///
+    /// assert_eq!(rendered, vec![1, 2, 3]);
+    /// ```
+    fn with_scratch<S, F>(self, scratch: S, fill: F) -> WithScratch<Self, S, F>
+    where
+        F: FnMut(&mut S, Self::Item),
+    {
+        WithScratch { iter: self, scratch, fill }
+    }
+}
 
-    // TODO: unzip
+/// An internal iterator that can also iterate over its elements in reverse
+/// order.
+///
+/// This is internal-iterator equivalent of
+/// [`std::iter::DoubleEndedIterator`]. Because internal iterators are consumed
+/// by iteration, iterating from both ends at once is not possible - instead,
+/// the iterator can be driven either from the front with
+/// [`InternalIterator::try_for_each`], or from the back with
+/// [`DoubleEndedInternalIterator::try_rfor_each`].
+pub trait DoubleEndedInternalIterator: InternalIterator {
+    /// Applies function to each element of the iterator, starting from the
+    /// back. Stops early if the function returns `ControlFlow::Break`.
+    ///
+    /// ```
+    /// # use internal_iterator::{DoubleEndedInternalIterator, IteratorExt};
+    /// # use std::ops::ControlFlow;
+    /// let a = [1, 2, 3, 4, 5, 6];
+    /// let mut collected = Vec::new();
+    ///
+    /// let result = a.iter().into_internal().try_rfor_each(|&x| {
+    ///     collected.push(x);
+    ///     if x == 4 {
+    ///         ControlFlow::Break("stopped!")
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(collected, [6, 5, 4]);
+    /// assert_eq!(result, ControlFlow::Break("stopped!"));
+    /// ```
+    fn try_rfor_each<R, F>(self, f: F) -> ControlFlow<R>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<R>;
+
+    /// Returns the last element of the iterator.
+    ///
+    /// Unlike [`InternalIterator::last`], this only needs to look at the last
+    /// element instead of traversing the whole iterator.
+    ///
+    /// ```
+    /// # use internal_iterator::{DoubleEndedInternalIterator, InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3];
+    /// assert_eq!(a.iter().into_internal().map(|x| x * 2).next_back(), Some(6));
+    /// ```
+    fn next_back(self) -> Option<Self::Item> {
+        self.rfind_map(Some)
+    }
+
+    /// Creates an iterator that yields the elements in reverse order.
+    ///
+    /// ```
+    /// # use internal_iterator::{DoubleEndedInternalIterator, InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3];
+    ///
+    /// let v = a.iter().into_internal().rev().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v, vec![&3, &2, &1]);
+    /// ```
+    fn rev(self) -> Rev<Self> {
+        Rev { iter: self }
+    }
+
+    /// Returns the last element of the iterator that matches the predicate.
+    ///
+    /// ```
+    /// # use internal_iterator::{DoubleEndedInternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 4];
+    ///
+    /// assert_eq!(a.iter().into_internal().rfind(|&&x| x % 2 == 1), Some(&3));
+    ///
+    /// assert_eq!(a.iter().into_internal().rfind(|&&x| x == 5), None);
+    /// ```
+    fn rfind<F>(self, mut f: F) -> Option<Self::Item>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.rfind_map(|item| {
+            if f(&item) {
+                Some(item)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Applies function to the elements of iterator, starting from the back,
+    /// and returns the first non-none result.
+    ///
+    /// ```
+    /// # use internal_iterator::{DoubleEndedInternalIterator, IteratorExt};
+    /// let a = ["lol", "2", "NaN", "4", "five"];
+    ///
+    /// let parsed = a
+    ///     .iter()
+    ///     .into_internal()
+    ///     .rfind_map(|x| x.parse().ok());
+    ///
+    /// assert_eq!(parsed, Some(4));
+    /// ```
+    fn rfind_map<R, F>(self, mut f: F) -> Option<R>
+    where
+        F: FnMut(Self::Item) -> Option<R>,
+    {
+        let value = self.try_rfor_each(|item| {
+            if let Some(value) = f(item) {
+                ControlFlow::Break(value)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        match value {
+            ControlFlow::Continue(()) => None,
+            ControlFlow::Break(value) => Some(value),
+        }
+    }
+
+    /// Folds every element into an accumulator, starting from the back.
+    ///
+    /// ```
+    /// # use internal_iterator::{DoubleEndedInternalIterator, IteratorExt};
+    /// let a = ["a", "b", "c"];
+    ///
+    /// let joined = a.iter().into_internal().rfold(String::new(), |acc, x| acc + x);
+    ///
+    /// assert_eq!(joined, "cba");
+    /// ```
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = Some(init);
+        let _ = self.try_rfor_each::<core::convert::Infallible, _>(|item| {
+            acc = acc.take().map(|acc| f(acc, item));
+            ControlFlow::Continue(())
+        });
+        acc.unwrap()
+    }
+
+    /// Returns the index of the last element matching the predicate. The
+    /// index is counted from the front of the iterator.
+    ///
+    /// The predicate is not called for elements in front of the match, but
+    /// these elements still need to be visited to compute the index.
+    ///
+    /// ```
+    /// # use internal_iterator::{DoubleEndedInternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 2, 1];
+    ///
+    /// assert_eq!(a.iter().into_internal().rposition(|&x| x == 2), Some(3));
+    ///
+    /// assert_eq!(a.iter().into_internal().rposition(|&x| x == 5), None);
+    /// ```
+    fn rposition<F>(self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        let mut position = None;
+        let _ = self.try_rfor_each::<core::convert::Infallible, _>(|item| {
+            match &mut position {
+                Some(position) => *position += 1,
+                None if f(item) => position = Some(0),
+                None => {}
+            }
+            ControlFlow::Continue(())
+        });
+        position
+    }
+}
+
+/// An internal iterator that knows its exact length.
+///
+/// This is internal-iterator equivalent of [`std::iter::ExactSizeIterator`].
+/// Implementors must return an exact `(len, Some(len))` from
+/// [`InternalIterator::size_hint`].
+pub trait ExactSizeInternalIterator: InternalIterator {
+    /// Returns the exact number of elements the iterator will yield, without
+    /// iterating over them.
+    ///
+    /// Unlike [`InternalIterator::count`], this does not run any closures in
+    /// the pipeline.
+    ///
+    /// ```
+    /// # use internal_iterator::{ExactSizeInternalIterator, InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(a.iter().into_internal().map(|x| x * 2).skip(1).len(), 4);
+    /// ```
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        assert_eq!(upper, Some(lower));
+        lower
+    }
+
+    /// Returns `true` if the iterator will not yield any elements.
+    ///
+    /// ```
+    /// # use internal_iterator::{ExactSizeInternalIterator, InternalIterator, IteratorExt};
+    /// let a = [1, 2, 3];
+    ///
+    /// assert!(!a.iter().into_internal().is_empty());
+    /// assert!(a.iter().into_internal().skip(3).is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Conversion to an [`InternalIterator`].
@@ -802,6 +1532,40 @@ pub trait FromInternalIterator<A> {
         T: IntoInternalIterator<Item = A>;
 }
 
+/// Extending a collection with the contents of an [`InternalIterator`].
+///
+/// This is internal-iterator equivalent of [`std::iter::Extend`]. Unlike
+/// [`FromInternalIterator`], it allows feeding a collection one item at a
+/// time, which is what [`InternalIterator::unzip`] and
+/// [`InternalIterator::partition`] need.
+pub trait InternalExtend<A> {
+    /// Extends the collection with a single element.
+    fn extend_internal_one(&mut self, item: A);
+
+    /// Extends the collection with the contents of an iterator.
+    fn extend_internal<T>(&mut self, iter: T)
+    where
+        T: IntoInternalIterator<Item = A>,
+    {
+        iter.into_internal_iter().for_each(|item| self.extend_internal_one(item));
+    }
+}
+
+impl InternalExtend<()> for () {
+    fn extend_internal_one(&mut self, _: ()) {}
+}
+
+impl<A, B, ExtendA, ExtendB> InternalExtend<(A, B)> for (ExtendA, ExtendB)
+where
+    ExtendA: InternalExtend<A>,
+    ExtendB: InternalExtend<B>,
+{
+    fn extend_internal_one(&mut self, (a, b): (A, B)) {
+        self.0.extend_internal_one(a);
+        self.1.extend_internal_one(b);
+    }
+}
+
 impl<C, R, E> FromInternalIterator<Result<R, E>> for Result<C, E>
 where
     C: FromInternalIterator<R>,
@@ -813,8 +1577,7 @@ where
         let mut error = None;
         let c = C::from_iter(iter
             .into_internal_iter()
-            // FIXME: this could stop on first Err
-            .filter_map(|r| match r {
+            .map_while(|r| match r {
                 Ok(v) => Some(v),
                 Err(e) => {
                     error = Some(e);
@@ -828,6 +1591,59 @@ where
     }
 }
 
+impl<C, T> FromInternalIterator<Option<T>> for Option<C>
+where
+    C: FromInternalIterator<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoInternalIterator<Item = Option<T>>
+    {
+        let mut failed = false;
+        let c = C::from_iter(iter
+            .into_internal_iter()
+            .map_while(|o| {
+                failed = o.is_none();
+                o
+            }));
+        if failed { None } else { Some(c) }
+    }
+}
+
+impl<C, B, T> FromInternalIterator<ControlFlow<B, T>> for ControlFlow<B, C>
+where
+    C: FromInternalIterator<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoInternalIterator<Item = ControlFlow<B, T>>
+    {
+        let mut broken = None;
+        let c = C::from_iter(iter
+            .into_internal_iter()
+            .map_while(|flow| match flow {
+                ControlFlow::Continue(v) => Some(v),
+                ControlFlow::Break(b) => {
+                    broken = Some(b);
+                    None
+                }
+            }));
+        match broken {
+            Some(b) => ControlFlow::Break(b),
+            None => ControlFlow::Continue(c),
+        }
+    }
+}
+
+impl FromInternalIterator<()> for () {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoInternalIterator<Item = ()>
+    {
+        iter.into_internal_iter().for_each(|()| {});
+    }
+}
+
 fn max_by<A, C: FnMut(&A, &A) -> Ordering>(x: A, y: A, mut compare: C) -> A {
     match compare(&x, &y) {
         Ordering::Less => y,
@@ -1,14 +1,15 @@
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
-use crate::{FromInternalIterator, InternalIterator, IntoInternalIterator, IteratorExt};
+use crate::{FromInternalIterator, InternalExtend, InternalIterator, IntoInternalIterator, IteratorExt};
 
 impl<A: Eq + Hash> FromInternalIterator<A> for HashSet<A> {
     fn from_iter<T>(iter: T) -> Self
     where
         T: IntoInternalIterator<Item = A>
     {
-        let mut result = HashSet::new();
-        iter.into_internal_iter().for_each(|item| {
+        let iter = iter.into_internal_iter();
+        let mut result = HashSet::with_capacity(iter.size_hint().0);
+        iter.for_each(|item| {
             result.insert(item);
         });
         result
@@ -20,14 +21,49 @@ impl<K: Eq + Hash, V> FromInternalIterator<(K, V)> for HashMap<K, V> {
     where
         T: IntoInternalIterator<Item = (K, V)>
     {
-        let mut result = HashMap::new();
-        iter.into_internal_iter().for_each(|(k, v)| {
+        let iter = iter.into_internal_iter();
+        let mut result = HashMap::with_capacity(iter.size_hint().0);
+        iter.for_each(|(k, v)| {
             result.insert(k, v);
         });
         result
     }
 }
 
+impl<A: Eq + Hash> InternalExtend<A> for HashSet<A> {
+    fn extend_internal_one(&mut self, item: A) {
+        self.insert(item);
+    }
+
+    fn extend_internal<T>(&mut self, iter: T)
+    where
+        T: IntoInternalIterator<Item = A>
+    {
+        let iter = iter.into_internal_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|item| {
+            self.insert(item);
+        });
+    }
+}
+
+impl<K: Eq + Hash, V> InternalExtend<(K, V)> for HashMap<K, V> {
+    fn extend_internal_one(&mut self, (k, v): (K, V)) {
+        self.insert(k, v);
+    }
+
+    fn extend_internal<T>(&mut self, iter: T)
+    where
+        T: IntoInternalIterator<Item = (K, V)>
+    {
+        let iter = iter.into_internal_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
 crate::into_internal_impls! {
     ['a, T] &'a HashSet<T>,
     [T] HashSet<T>,
@@ -0,0 +1,180 @@
+use core::num::{Saturating, Wrapping};
+use core::time::Duration;
+use crate::InternalIterator;
+
+/// Trait to represent types that can be created by summing up an internal
+/// iterator.
+///
+/// This is internal-iterator equivalent of [`std::iter::Sum`]. It is used to
+/// implement [`InternalIterator::sum`].
+pub trait InternalSum<A = Self>: Sized {
+    /// Sums up the elements of an iterator.
+    fn sum<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = A>;
+}
+
+/// Trait to represent types that can be created by multiplying elements of an
+/// internal iterator.
+///
+/// This is internal-iterator equivalent of [`std::iter::Product`]. It is used
+/// to implement [`InternalIterator::product`].
+pub trait InternalProduct<A = Self>: Sized {
+    /// Multiplies the elements of an iterator.
+    fn product<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = A>;
+}
+
+macro_rules! sum_product_impls {
+    ($zero:expr, $one:expr, $($a:ty)*) => {
+        $(
+            impl InternalSum for $a {
+                fn sum<I>(iter: I) -> Self
+                where
+                    I: InternalIterator<Item = Self>,
+                {
+                    iter.fold($zero, |a, b| a + b)
+                }
+            }
+
+            impl InternalProduct for $a {
+                fn product<I>(iter: I) -> Self
+                where
+                    I: InternalIterator<Item = Self>,
+                {
+                    iter.fold($one, |a, b| a * b)
+                }
+            }
+
+            impl<'a> InternalSum<&'a $a> for $a {
+                fn sum<I>(iter: I) -> Self
+                where
+                    I: InternalIterator<Item = &'a Self>,
+                {
+                    iter.fold($zero, |a, b| a + *b)
+                }
+            }
+
+            impl<'a> InternalProduct<&'a $a> for $a {
+                fn product<I>(iter: I) -> Self
+                where
+                    I: InternalIterator<Item = &'a Self>,
+                {
+                    iter.fold($one, |a, b| a * *b)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! wrapper_sum_product_impls {
+    ($($a:ty)*) => {
+        sum_product_impls! { Wrapping(0), Wrapping(1), $(Wrapping<$a>)* }
+        sum_product_impls! { Saturating(0), Saturating(1), $(Saturating<$a>)* }
+    };
+}
+
+sum_product_impls! { 0, 1, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+// -0.0 is the additive identity for floats: 0.0 would turn a sum of -0.0
+// values into 0.0
+sum_product_impls! { -0.0, 1.0, f32 f64 }
+wrapper_sum_product_impls! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+impl InternalSum for Duration {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = Self>,
+    {
+        iter.fold(Duration::ZERO, |a, b| a + b)
+    }
+}
+
+impl<'a> InternalSum<&'a Duration> for Duration {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = &'a Self>,
+    {
+        iter.fold(Duration::ZERO, |a, b| a + *b)
+    }
+}
+
+impl<T, U> InternalSum<Option<U>> for Option<T>
+where
+    T: InternalSum<U>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = Option<U>>,
+    {
+        let mut failed = false;
+        let sum = T::sum(iter.map_while(|item| {
+            failed = item.is_none();
+            item
+        }));
+        if failed { None } else { Some(sum) }
+    }
+}
+
+impl<T, U> InternalProduct<Option<U>> for Option<T>
+where
+    T: InternalProduct<U>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = Option<U>>,
+    {
+        let mut failed = false;
+        let product = T::product(iter.map_while(|item| {
+            failed = item.is_none();
+            item
+        }));
+        if failed { None } else { Some(product) }
+    }
+}
+
+impl<T, U, E> InternalSum<Result<U, E>> for Result<T, E>
+where
+    T: InternalSum<U>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = Result<U, E>>,
+    {
+        let mut error = None;
+        let sum = T::sum(iter.map_while(|item| match item {
+            Ok(value) => Some(value),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        }));
+        match error {
+            Some(err) => Err(err),
+            None => Ok(sum),
+        }
+    }
+}
+
+impl<T, U, E> InternalProduct<Result<U, E>> for Result<T, E>
+where
+    T: InternalProduct<U>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: InternalIterator<Item = Result<U, E>>,
+    {
+        let mut error = None;
+        let product = T::product(iter.map_while(|item| match item {
+            Ok(value) => Some(value),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        }));
+        match error {
+            Some(err) => Err(err),
+            None => Ok(product),
+        }
+    }
+}
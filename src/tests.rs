@@ -55,6 +55,248 @@ fn take_empty() {
     assert_eq!(Iter.take(0).next(), None);
 }
 
+#[test]
+fn take_break_on_last_item() {
+    let found = [1, 2, 3].into_internal_iter().take(3).find(|&x| x == 3);
+    assert_eq!(found, Some(3));
+}
+
+#[test]
+fn lending_take_break_on_last_item() {
+    let found = [1, 2, 3]
+        .into_internal_iter()
+        .with_scratch(0, |buf, x| *buf = x)
+        .take(3)
+        .find_map(|buf| (*buf == 3).then_some(*buf));
+    assert_eq!(found, Some(3));
+}
+
+#[test]
+fn take_while_short_circuit() {
+    let mut visited = 0;
+    let count = (1..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .take_while(|&x| x < 4)
+        .count();
+    assert_eq!(count, 3);
+    // the first failing element is visited, but nothing after it
+    assert_eq!(visited, 4);
+
+    let mut visited = 0;
+    let count = (1..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .map_while(|x| if x < 4 { Some(x * 2) } else { None })
+        .count();
+    assert_eq!(count, 3);
+    assert_eq!(visited, 4);
+}
+
+#[test]
+fn skip_while_only_skips_prefix() {
+    let mut predicate_calls = 0;
+    let last = [1, 2, 5, 1, 2]
+        .into_internal_iter()
+        .skip_while(|&x| {
+            predicate_calls += 1;
+            x < 3
+        })
+        .last();
+    assert_eq!(last, Some(2));
+    assert_eq!(predicate_calls, 3);
+}
+
+#[test]
+fn try_fold_short_circuit() {
+    let mut visited = 0;
+    let result = (1..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .try_fold(0, |acc, x| if x == 4 { Err(x) } else { Ok(acc + x) });
+    assert_eq!(result, Err(4));
+    assert_eq!(visited, 4);
+
+    let result = (1..10)
+        .into_internal()
+        .try_fold(0, |acc, x| ControlFlow::<(), _>::Continue(acc + x));
+    assert_eq!(result, ControlFlow::Continue(45));
+}
+
+#[test]
+fn try_find_short_circuit() {
+    let mut visited = 0;
+    let result = (1..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .try_find(|&x| if x < 3 { Some(false) } else { None });
+    assert_eq!(result, None);
+    assert_eq!(visited, 3);
+
+    let result = (1..10)
+        .into_internal()
+        .try_find(|&x| Some(x == 5));
+    assert_eq!(result, Some(Some(5)));
+
+    let result = (1..10)
+        .into_internal()
+        .try_reduce(|x, y| if y < 5 { Ok(x + y) } else { Err(y) });
+    assert_eq!(result, Err(5));
+}
+
+#[test]
+fn sum_product_short_circuit() {
+    let mut visited = 0;
+    let sum = [Some(1), Some(2), None, Some(4)]
+        .into_internal_iter()
+        .inspect(|_| visited += 1)
+        .sum::<Option<i32>>();
+    assert_eq!(sum, None);
+    assert_eq!(visited, 3);
+
+    let mut visited = 0;
+    let product = [Ok(2), Err("bad"), Ok(4)]
+        .into_internal_iter()
+        .inspect(|_| visited += 1)
+        .product::<Result<i32, _>>();
+    assert_eq!(product, Err("bad"));
+    assert_eq!(visited, 2);
+
+    let sum = [Ok::<_, ()>(2), Ok(3)].into_internal_iter().sum::<Result<i32, _>>();
+    assert_eq!(sum, Ok(5));
+}
+
+#[test]
+fn sum_product_wrappers() {
+    use core::num::{Saturating, Wrapping};
+    use core::time::Duration;
+
+    let a = [Wrapping(200u8), Wrapping(100)];
+    assert_eq!(a.iter().into_internal().sum::<Wrapping<u8>>(), Wrapping(44));
+
+    let a = [Saturating(20u8), Saturating(20)];
+    assert_eq!(a.into_internal_iter().product::<Saturating<u8>>(), Saturating(255));
+
+    let a = [Duration::from_secs(1), Duration::from_millis(500)];
+    assert_eq!(a.iter().into_internal().sum::<Duration>(), Duration::from_millis(1500));
+
+    let a = [0.5f64, 2.0, 4.0];
+    assert_eq!(a.iter().into_internal().product::<f64>(), 4.0);
+}
+
+#[test]
+fn scan_short_circuit() {
+    let mut visited = 0;
+    let last = (1..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .scan(0, |total, x| {
+            *total += x;
+            if *total < 10 { Some(*total) } else { None }
+        })
+        .last();
+    assert_eq!(last, Some(6));
+    assert_eq!(visited, 4);
+}
+
+#[test]
+fn cycle_stops() {
+    let empty: [i32; 0] = [];
+    assert_eq!(empty.into_internal_iter().cycle().next(), None);
+
+    let visited = core::cell::Cell::new(0);
+    let nth = [1, 2, 3]
+        .into_internal_iter()
+        .inspect(|_| visited.set(visited.get() + 1))
+        .cycle()
+        .nth(4);
+    assert_eq!(nth, Some(2));
+    assert_eq!(visited.get(), 5);
+}
+
+#[test]
+fn size_hint_propagation() {
+    let a = [1, 2, 3, 4, 5];
+    let iter = || a.into_internal_iter();
+
+    assert_eq!(iter().size_hint(), (5, Some(5)));
+    assert_eq!(iter().map(|x| x * 2).enumerate().size_hint(), (5, Some(5)));
+    assert_eq!(iter().filter(|&x| x > 2).size_hint(), (0, Some(5)));
+    assert_eq!(iter().take(3).size_hint(), (3, Some(3)));
+    assert_eq!(iter().take(10).size_hint(), (5, Some(5)));
+    assert_eq!(iter().skip(2).size_hint(), (3, Some(3)));
+    assert_eq!(iter().skip(10).size_hint(), (0, Some(0)));
+    assert_eq!(iter().chain(iter()).size_hint(), (10, Some(10)));
+    assert_eq!(iter().step_by(2).size_hint(), (3, Some(3)));
+    assert_eq!(iter().cycle().size_hint(), (usize::MAX, None));
+    assert_eq!(iter().take(0).cycle().size_hint(), (0, Some(0)));
+    assert_eq!((0..).into_internal().take(4).size_hint(), (4, Some(4)));
+    assert_eq!(iter().flat_map(|x| [x, x]).size_hint(), (0, None));
+}
+
+#[test]
+fn exact_size_len() {
+    let a = [1, 2, 3, 4, 5];
+
+    let mut closure_calls = 0;
+    let iter = a
+        .iter()
+        .into_internal()
+        .map(|x| {
+            closure_calls += 1;
+            x * 2
+        })
+        .enumerate()
+        .skip(1)
+        .take(3)
+        .chain([10, 20].into_internal_iter().enumerate());
+    assert_eq!(iter.len(), 5);
+    drop(iter);
+    assert_eq!(closure_calls, 0);
+
+    assert_eq!(a.iter().into_internal().copied().len(), 5);
+    assert_eq!(a.iter().into_internal().cloned().inspect(|_| ()).rev().len(), 5);
+    assert_eq!(a.into_internal_iter().skip(10).len(), 0);
+    assert_eq!(a.into_internal_iter().skip(2).count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn chain_len_overflow() {
+    let _ = (0..usize::MAX).into_internal().chain((0..1).into_internal()).len();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn collect_reserves_capacity() {
+    use alloc::vec::Vec;
+
+    let v = (0..100).into_internal().map(|x| x * 2).collect::<Vec<_>>();
+    assert_eq!(v.len(), 100);
+    // `with_capacity` only promises at least 100, but growing while pushing
+    // would have to go well past it
+    assert!(v.capacity() >= 100 && v.capacity() < 2 * 100);
+}
+
+#[test]
+fn either_forwarding() {
+    fn pick(left: bool) -> impl ExactSizeInternalIterator<Item = i32> + DoubleEndedInternalIterator {
+        if left {
+            (0..5).into_internal().left_internal()
+        } else {
+            [7, 8].into_internal_iter().map(|x| x * 2).right_internal()
+        }
+    }
+
+    assert_eq!(pick(true).size_hint(), (5, Some(5)));
+    assert_eq!(pick(false).len(), 2);
+    assert_eq!(pick(true).count(), 5);
+    assert_eq!(pick(true).nth(3), Some(3));
+    assert_eq!(pick(false).last(), Some(16));
+    assert_eq!(pick(false).next_back(), Some(16));
+    assert_eq!(pick(true).rev().next(), Some(4));
+}
+
 #[test]
 fn map_side_effects_preserved() {
     let mut closure_calls = 0;
@@ -91,6 +333,46 @@ fn map_side_effects_preserved() {
     assert_eq!(closure_calls, 15);
 }
 
+#[test]
+fn flatten_specializations() {
+    let nested = [[1, 2], [3, 4], [5, 6]];
+
+    assert_eq!(nested.into_internal_iter().flatten().count(), 6);
+    assert_eq!(nested.into_internal_iter().flatten().last(), Some(6));
+
+    let nested = [&[1, 2][..], &[], &[3], &[]];
+    assert_eq!(nested.into_internal_iter().flatten().last(), Some(&3));
+    assert_eq!(nested.into_internal_iter().flatten().nth(2), Some(&3));
+}
+
+#[test]
+fn step_by_specializations() {
+    let mut closure_calls = 0;
+    let count = (0..10)
+        .into_internal()
+        .map(|x| {
+            closure_calls += 1;
+            x
+        })
+        .step_by(3)
+        .count();
+    assert_eq!(count, 4);
+    assert_eq!(closure_calls, 10);
+
+    assert_eq!((0..0).into_internal().step_by(3).count(), 0);
+    assert_eq!((0..9).into_internal().step_by(3).count(), 3);
+    assert_eq!((0..10).into_internal().step_by(3).nth(2), Some(6));
+    assert_eq!((0..10).into_internal().step_by(3).nth(4), None);
+    assert_eq!((0..10).into_internal().step_by(usize::MAX).nth(2), None);
+    assert_eq!((0..10).into_internal().step_by(4).last(), Some(8));
+}
+
+#[test]
+#[should_panic]
+fn step_by_zero() {
+    let _ = (0..10).into_internal().step_by(0);
+}
+
 #[test]
 fn clone_side_effects_preserved() {
     use core::cell::Cell;
@@ -135,6 +417,558 @@ fn clone_side_effects_preserved() {
     assert_eq!(clones.get(), 4);
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn unzip_partition() {
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    let (a, b): (Vec<_>, BTreeSet<_>) = [(1, 'b'), (2, 'a'), (3, 'b')]
+        .into_internal_iter()
+        .unzip();
+    assert_eq!(a, vec![1, 2, 3]);
+    assert_eq!(b.into_iter().collect::<Vec<_>>(), vec!['a', 'b']);
+
+    let ((a, b), c): ((Vec<_>, Vec<_>), Vec<_>) = [((1, 2), 3), ((4, 5), 6)]
+        .into_internal_iter()
+        .unzip();
+    assert_eq!((a, b, c), (vec![1, 4], vec![2, 5], vec![3, 6]));
+
+    let (small, large): (Vec<_>, Vec<_>) = (0..6).into_internal().partition(|&x| x < 2);
+    assert_eq!(small, vec![0, 1]);
+    assert_eq!(large, vec![2, 3, 4, 5]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn collect_short_circuit() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    let mut visited = 0;
+    let result = (0..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .map(|x| if x == 3 { Err(x) } else { Ok(x) })
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(result, Err(3));
+    assert_eq!(visited, 4);
+
+    let mut visited = 0;
+    let result = (0..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .map(|x| if x == 3 { None } else { Some(x) })
+        .collect::<Option<Vec<_>>>();
+    assert_eq!(result, None);
+    assert_eq!(visited, 4);
+
+    let mut visited = 0;
+    let result = (0..10)
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .map(|x| if x == 3 { ControlFlow::Break(x) } else { ControlFlow::Continue(x) })
+        .collect::<ControlFlow<_, Vec<_>>>();
+    assert_eq!(result, ControlFlow::Break(3));
+    assert_eq!(visited, 4);
+
+    let result = (0..10)
+        .into_internal()
+        .map(|x| if x == 3 { Err(x) } else { Ok(()) })
+        .collect::<Result<(), _>>();
+    assert_eq!(result, Err(3));
+
+    let result = (0..3)
+        .into_internal()
+        .map(Some)
+        .collect::<Option<Vec<_>>>();
+    assert_eq!(result, Some(vec![0, 1, 2]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn double_ended_pipelines() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    let a = [1, 2, 3, 4, 5];
+
+    let v = a
+        .iter()
+        .into_internal()
+        .chain([6, 7].iter().into_internal())
+        .filter(|&&x| x % 2 == 1)
+        .copied()
+        .rev()
+        .collect::<Vec<_>>();
+    assert_eq!(v, vec![7, 5, 3, 1]);
+
+    let v = a
+        .iter()
+        .into_internal()
+        .enumerate()
+        .map(|(idx, &x)| idx * 10 + x)
+        .rev()
+        .collect::<Vec<_>>();
+    assert_eq!(v, vec![45, 34, 23, 12, 1]);
+
+    let v = [vec![1, 2], vec![3]]
+        .iter()
+        .into_internal()
+        .flat_map(|v| v.iter().into_internal().cloned())
+        .rev()
+        .collect::<Vec<_>>();
+    assert_eq!(v, vec![3, 2, 1]);
+
+    let mut visited = 0;
+    let last = a
+        .iter()
+        .into_internal()
+        .inspect(|_| visited += 1)
+        .rev()
+        .last();
+    assert_eq!(last, Some(&1));
+    assert_eq!(visited, 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn boxed_short_circuit() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    let mut visited = 0;
+    let iters: Vec<BoxedInternalIterator<'_, i32>> = vec![
+        (0..3).into_internal().boxed(),
+        (10..20).into_internal().inspect(|_| visited += 1).boxed(),
+    ];
+    let found = iters
+        .into_internal_iter()
+        .flatten()
+        .find(|&x| x == 12);
+    assert_eq!(found, Some(12));
+    assert_eq!(visited, 3);
+
+    let boxed = (0..5).into_internal().boxed_send();
+    assert_eq!(boxed.size_hint(), (5, Some(5)));
+    let sum = std::thread::spawn(move || boxed.sum::<i32>()).join().unwrap();
+    assert_eq!(sum, 10);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn walk_orders_and_short_circuit() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // node n has children 2n and 2n + 1, up to 7
+    let children = |&n: &u32| [2 * n, 2 * n + 1].into_internal_iter().filter(|&c| c <= 7);
+
+    let preorder = walk(1, children).collect::<Vec<_>>();
+    assert_eq!(preorder, vec![1, 2, 4, 5, 3, 6, 7]);
+
+    let postorder = walk(1, children).postorder().collect::<Vec<_>>();
+    assert_eq!(postorder, vec![4, 5, 2, 6, 7, 3, 1]);
+
+    let mut expanded = 0;
+    let found = walk(1, |n: &u32| {
+        expanded += 1;
+        children(n)
+    })
+    .find(|&n| n == 4);
+    assert_eq!(found, Some(4));
+    // the found node itself is not expanded
+    assert_eq!(expanded, 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn walk_pruning() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    let children = |&n: &u32| [2 * n, 2 * n + 1].into_internal_iter().filter(|&c| c <= 7);
+
+    let pruned = walk(1, children).postorder().prune(|&n| n == 3).collect::<Vec<_>>();
+    assert_eq!(pruned, vec![4, 5, 2, 3, 1]);
+
+    let mut visited = Vec::new();
+    let result = walk(1, children).try_visit(|&n| {
+        visited.push(n);
+        if n == 2 { WalkControl::SkipChildren } else { WalkControl::<()>::Continue }
+    });
+    assert_eq!(result, ControlFlow::Continue(()));
+    assert_eq!(visited, vec![1, 2, 3, 6, 7]);
+
+    // visiting does not need to clone nodes
+    struct Node(u32);
+    let mut visited = Vec::new();
+    let node_children = |n: &Node| {
+        [2 * n.0, 2 * n.0 + 1].into_internal_iter().filter(|&c| c <= 7).map(Node)
+    };
+    let result = walk(Node(1), node_children)
+        .try_visit(|n| {
+            visited.push(n.0);
+            if n.0 == 5 { WalkControl::Break(n.0) } else { WalkControl::Continue }
+        });
+    assert_eq!(result, ControlFlow::Break(5));
+    assert_eq!(visited, vec![1, 2, 4, 5]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn walk_deep_tree() {
+    const DEPTH: u32 = 1_000_000;
+
+    let count = walk(0, |&n: &u32| if n < DEPTH { Some(n + 1) } else { None }).count();
+    assert_eq!(count, DEPTH as usize + 1);
+
+    let first = walk(0, |&n: &u32| if n < DEPTH { Some(n + 1) } else { None })
+        .postorder()
+        .next();
+    assert_eq!(first, Some(DEPTH));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn graph_traversals() {
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // a cycle 0 -> 1 -> 2 -> 0, with 1 -> 3 and 3 -> 1
+    let edges = [vec![1], vec![2, 3], vec![0], vec![1]];
+    let neighbors = |&n: &usize| edges[n].iter().into_internal().copied();
+
+    assert_eq!(dfs(0, neighbors).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(bfs(3, neighbors).collect::<Vec<_>>(), vec![3, 1, 2, 0]);
+
+    let visits = dfs(0, neighbors).visits().collect::<Vec<_>>();
+    assert_eq!(visits[3], Visit { node: 3, depth: 2, parent: Some(1) });
+
+    let seen = [2].into_internal_iter().collect::<BTreeSet<_>>();
+    assert_eq!(bfs(0, neighbors).with_visited(seen).collect::<Vec<_>>(), vec![0, 1, 3]);
+
+    let mut expanded = 0;
+    let found = bfs(0, |n: &usize| {
+        expanded += 1;
+        neighbors(n)
+    })
+    .find(|&n| n == 1);
+    assert_eq!(found, Some(1));
+    // the found node itself is not expanded
+    assert_eq!(expanded, 1);
+
+    let mut expanded = 0;
+    let found = dfs(0, |n: &usize| {
+        expanded += 1;
+        neighbors(n)
+    })
+    .find(|&n| n == 2);
+    assert_eq!(found, Some(2));
+    assert_eq!(expanded, 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn topo_order_and_components() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // 0 depends on 1 and 2, 1 and 2 depend on 3, 4 depends on 0
+    let deps = [vec![1, 2], vec![3], vec![3], vec![], vec![0]];
+    let edges = |&n: &usize| deps[n].iter().into_internal().copied();
+
+    let order = topo_order([4, 2], edges).collect::<Result<Vec<_>, _>>();
+    assert_eq!(order, Ok(vec![3, 1, 2, 0, 4]));
+
+    let first = topo_order([4], edges).next();
+    assert_eq!(first, Some(Ok(3)));
+
+    // 1 -> 2 -> 3 -> 1, 0 -> 1
+    let deps = [vec![1], vec![2], vec![3], vec![1]];
+    let edges = |&n: &usize| deps[n].iter().into_internal().copied();
+
+    let mut yielded = Vec::new();
+    let order = topo_order([0], edges)
+        .inspect(|item| yielded.push(item.clone()))
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(order, Err(CycleError { cycle: vec![1, 2, 3] }));
+    assert_eq!(yielded.len(), 1);
+
+    let components = strongly_connected_components((0..4).into_internal(), edges).collect::<Vec<_>>();
+    assert_eq!(components, vec![vec![1, 2, 3], vec![0]]);
+
+    let count = strongly_connected_components((0..1000).into_internal(), |&n: &usize| [(n + 1) % 1000]).count();
+    assert_eq!(count, 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn graph_traversal_hash_set() {
+    use std::collections::HashSet;
+
+    // only implements `Hash` and `Eq`, so the default `BTreeSet` can't be used
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    struct Node(u32);
+
+    let neighbors = |n: &Node| [Node((n.0 + 1) % 100_000), Node((n.0 * 7) % 100_000)];
+
+    let count = dfs(Node(0), neighbors).with_visited(HashSet::new()).count();
+    assert_eq!(count, 100_000);
+
+    let count = bfs(Node(0), neighbors).with_visited(HashSet::new()).count();
+    assert_eq!(count, 100_000);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn lending_scratch_reuse() {
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // lends out each window of the slice, reversed in place in a shared buffer
+    struct ReversedWindows<'s> {
+        slice: &'s [i32],
+        size: usize,
+    }
+
+    impl<'a> LendingItem<'a> for ReversedWindows<'_> {
+        type Item = &'a mut [i32];
+    }
+
+    impl LendingInternalIterator for ReversedWindows<'_> {
+        fn try_for_each<R, F>(self, mut f: F) -> ControlFlow<R>
+        where
+            F: for<'a> FnMut(&'a mut [i32]) -> ControlFlow<R>,
+        {
+            let mut buf = Vec::with_capacity(self.size);
+            for window in self.slice.windows(self.size) {
+                buf.clear();
+                buf.extend(window.iter().rev());
+                f(&mut buf)?;
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let data = [1, 2, 3, 4, 5];
+    let windows = ReversedWindows { slice: &data, size: 3 }
+        .enumerate()
+        .skip(1)
+        .take(1)
+        .map(|(i, w)| (i, w.to_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(windows, vec![(1, vec![4, 3, 2])]);
+
+    let mut filled = 0;
+    let mut buffers = Vec::new();
+    let words = ["alpha", "be", "gamma", "delta"];
+    let found = words
+        .iter()
+        .into_internal()
+        .with_scratch(String::with_capacity(16), |buf, word| {
+            filled += 1;
+            buf.clear();
+            buf.push_str(word);
+        })
+        .filter(|buf| buf.len() == 5)
+        .find_map(|buf| {
+            buffers.push(buf.as_ptr());
+            buf.starts_with('g').then(|| buf.clone())
+        });
+    assert_eq!(found.as_deref(), Some("gamma"));
+    assert_eq!(filled, 3);
+    assert_eq!(buffers.len(), 2);
+    assert_eq!(buffers[0], buffers[1]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn lending_scratch_borrows() {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    let rows = [String::from("a,b,c"), String::from("d,e")];
+    let mut first_fields = Vec::new();
+    rows.iter()
+        .into_internal()
+        .with_scratch(Vec::new(), |fields: &mut Vec<&str>, row| {
+            fields.clear();
+            fields.extend(row.split(','));
+        })
+        .for_each(|fields| first_fields.push((fields[0], fields.len())));
+    assert_eq!(first_fields, [("a", 3), ("d", 2)]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_lines_and_records() {
+    use std::io::{self, BufRead, Cursor, Read};
+
+    let input = Cursor::new("first\r\nsecond\n\nlast");
+    let lines = read_lines(input)
+        .map(|line| line.unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(lines, ["first", "second", "", "last"]);
+
+    let input = Cursor::new("first\r\nlast\r");
+    let lines = read_lines(input)
+        .map(|line| line.unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(lines, ["first", "last\r"]);
+
+    let input = Cursor::new(b"ok\n\xff\nnever\n");
+    let lines = read_lines(input)
+        .map(|line| line.map(str::len).map_err(|err| err.kind()))
+        .collect::<Vec<_>>();
+    assert_eq!(lines, [Ok(2), Err(io::ErrorKind::InvalidData)]);
+
+    let records = read_split(Cursor::new(b"a,,bc,"), b',')
+        .map(|record| record.unwrap().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(records, [&b"a"[..], b"", b"bc"]);
+
+    // fails after yielding its data once, and counts how often it was read
+    struct Failing {
+        data: Cursor<&'static [u8]>,
+        reads: usize,
+    }
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            unreachable!("records are read through BufRead")
+        }
+    }
+
+    impl BufRead for Failing {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            self.reads += 1;
+            if self.data.position() as usize == self.data.get_ref().len() {
+                return Err(io::Error::other("disk on fire"));
+            }
+            self.data.fill_buf()
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.data.consume(amt);
+        }
+    }
+
+    let mut seen = Vec::new();
+    let failing = Failing { data: Cursor::new(b"1\n2\n"), reads: 0 };
+    let result = read_lines(failing).try_for_each_line(|line| {
+        seen.push(line.to_string());
+        ControlFlow::<io::Error>::Continue(())
+    });
+    assert_eq!(seen, ["1", "2"]);
+    match result {
+        ControlFlow::Break(err) => assert_eq!(err.to_string(), "disk on fire"),
+        ControlFlow::Continue(()) => panic!("error was not reported"),
+    }
+
+    let mut failing = Failing { data: Cursor::new(b"1\n2\n"), reads: 0 };
+    let first = read_lines(&mut failing).find_map(|line| line.ok().map(str::to_string));
+    assert_eq!(first.as_deref(), Some("1"));
+    assert_eq!(failing.reads, 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn walk_dir_options() {
+    use std::fs;
+    use std::io;
+    use std::path::PathBuf;
+
+    // removes the directory even if an assertion fails
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    let temp = TempDir(std::env::temp_dir().join(format!("internal-iterator-walk-dir-{}", std::process::id())));
+    let root = temp.0.clone();
+    let _ = fs::remove_dir_all(&root);
+    for dir in ["a/b", "skip", "d"] {
+        fs::create_dir_all(root.join(dir)).unwrap();
+    }
+    for file in ["a/x.txt", "a/b/y.txt", "c.txt", "skip/z.txt"] {
+        fs::write(root.join(file), file).unwrap();
+    }
+
+    let relative = |entry: io::Result<WalkDirEntry>| {
+        let entry = entry.unwrap();
+        let path = entry.entry.path().strip_prefix(&root).unwrap().to_path_buf();
+        (path.to_str().unwrap().replace('\\', "/"), entry.depth)
+    };
+
+    let all = walk_dir(&root).sort_by_file_name().map(relative).collect::<Vec<_>>();
+    assert_eq!(all, [
+        ("a".to_string(), 1),
+        ("a/b".to_string(), 2),
+        ("a/b/y.txt".to_string(), 3),
+        ("a/x.txt".to_string(), 2),
+        ("c.txt".to_string(), 1),
+        ("d".to_string(), 1),
+        ("skip".to_string(), 1),
+        ("skip/z.txt".to_string(), 2),
+    ]);
+
+    let shallow = walk_dir(&root)
+        .sort_by_file_name()
+        .max_depth(1)
+        .map(|entry| relative(entry).0)
+        .collect::<Vec<_>>();
+    assert_eq!(shallow, ["a", "c.txt", "d", "skip"]);
+
+    let pruned = walk_dir(&root)
+        .sort_by_file_name()
+        .prune(|entry| entry.file_name() == "skip" || entry.file_name() == "b")
+        .map(|entry| relative(entry).0)
+        .collect::<Vec<_>>();
+    assert_eq!(pruned, ["a", "a/b", "a/x.txt", "c.txt", "d", "skip"]);
+
+    let first_file = walk_dir(&root)
+        .sort_by_file_name()
+        .find_map(|entry| entry.ok().filter(|e| e.entry.file_type().unwrap().is_file()));
+    assert_eq!(first_file.unwrap().entry.file_name(), "y.txt");
+
+    let missing = walk_dir(root.join("missing")).collect::<Vec<_>>();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].as_ref().unwrap_err().kind(), io::ErrorKind::NotFound);
+
+    let mut visited = 0;
+    let result = walk_dir(root.join("missing")).try_for_each_entry(|_| {
+        visited += 1;
+        ControlFlow::<io::Error>::Continue(())
+    });
+    assert!(matches!(result, ControlFlow::Break(err) if err.kind() == io::ErrorKind::NotFound));
+    assert_eq!(visited, 0);
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(&root, root.join("d/loop")).unwrap();
+
+        let unfollowed = walk_dir(&root).filter(|entry| entry.is_err()).count();
+        assert_eq!(unfollowed, 0);
+
+        let mut errors = 0;
+        let followed = walk_dir(&root)
+            .follow_links(true)
+            .filter_map(|entry| entry.map_err(|_| errors += 1).ok())
+            .map(|entry| entry.entry.path())
+            .collect::<Vec<PathBuf>>();
+        assert_eq!(errors, 1);
+        assert!(followed.contains(&root.join("d/loop")));
+        assert!(!followed.contains(&root.join("d/loop/c.txt")));
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn readme_example() {
@@ -183,7 +1017,7 @@ fn readme_example() {
             loop {
                 match self.position.as_slice() {
                     [0, rest @ ..] => {
-                        let current_tree = find_subtree(&self.tree, &rest);
+                        let current_tree = find_subtree(&self.tree, rest);
                         if let Some(tree) = current_tree {
                             let result = Some(tree.0);
                             if !tree.1.is_empty() {
@@ -0,0 +1,113 @@
+use core::convert::Infallible;
+use core::ops::ControlFlow;
+
+/// A stable stand-in for the unstable `core::ops::Try` trait.
+///
+/// It is used by short-circuiting methods such as
+/// [`InternalIterator::try_fold`](crate::InternalIterator::try_fold) to accept
+/// closures returning [`Option`], [`Result`], or [`ControlFlow`].
+pub trait Try {
+    /// The value produced when the operation succeeds.
+    type Output;
+    /// The value carried out when the operation short-circuits.
+    type Residual;
+
+    /// Wraps a successful value.
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Reconstructs the short-circuiting value from its residual.
+    fn from_residual(residual: Self::Residual) -> Self;
+
+    /// Decides whether to continue with the output or to stop with the
+    /// residual.
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+/// Maps a [`Try::Residual`] back to a [`Try`] type with a different output.
+///
+/// This is a stable stand-in for the unstable `core::ops::Residual` trait, and
+/// allows methods such as
+/// [`InternalIterator::try_find`](crate::InternalIterator::try_find) to return
+/// `Option<Option<T>>` when given a closure returning `Option<bool>`.
+pub trait Residual<O> {
+    /// The [`Try`] type with this residual and `O` as the output.
+    type TryType: Try<Output = O, Residual = Self>;
+}
+
+impl<T> Try for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    fn from_output(output: T) -> Self {
+        Some(output)
+    }
+
+    fn from_residual(_: Option<Infallible>) -> Self {
+        None
+    }
+
+    fn branch(self) -> ControlFlow<Option<Infallible>, T> {
+        match self {
+            Some(value) => ControlFlow::Continue(value),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+impl<O> Residual<O> for Option<Infallible> {
+    type TryType = Option<O>;
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Ok(never) => match never {},
+            Err(err) => Err(err),
+        }
+    }
+
+    fn branch(self) -> ControlFlow<Result<Infallible, E>, T> {
+        match self {
+            Ok(value) => ControlFlow::Continue(value),
+            Err(err) => ControlFlow::Break(Err(err)),
+        }
+    }
+}
+
+impl<O, E> Residual<O> for Result<Infallible, E> {
+    type TryType = Result<O, E>;
+}
+
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, Infallible>;
+
+    fn from_output(output: C) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    fn from_residual(residual: ControlFlow<B, Infallible>) -> Self {
+        match residual {
+            ControlFlow::Continue(never) => match never {},
+            ControlFlow::Break(value) => ControlFlow::Break(value),
+        }
+    }
+
+    fn branch(self) -> ControlFlow<ControlFlow<B, Infallible>, C> {
+        match self {
+            ControlFlow::Continue(value) => ControlFlow::Continue(value),
+            ControlFlow::Break(value) => ControlFlow::Break(ControlFlow::Break(value)),
+        }
+    }
+}
+
+impl<O, B> Residual<O> for ControlFlow<B, Infallible> {
+    type TryType = ControlFlow<B, O>;
+}
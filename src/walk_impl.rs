@@ -0,0 +1,246 @@
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+use crate::{InternalIterator, IntoInternalIterator};
+
+/// An iterator returned by [`walk`].
+#[derive(Clone)]
+pub struct Walk<N, F> {
+    root: N,
+    children: F,
+    postorder: bool,
+}
+
+impl<N, F> Walk<N, F> {
+    /// Yields every node before its children. This is the default.
+    pub fn preorder(self) -> Self {
+        Walk { postorder: false, ..self }
+    }
+
+    /// Yields every node after its children.
+    pub fn postorder(self) -> Self {
+        Walk { postorder: true, ..self }
+    }
+}
+
+/// Controls how [`Walk::try_visit`] continues after visiting a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalkControl<R> {
+    /// Continue the walk, including the children of the visited node.
+    Continue,
+    /// Continue the walk, but skip the children of the visited node.
+    SkipChildren,
+    /// Stop the walk with the given value.
+    Break(R),
+}
+
+impl<N, F, C> Walk<N, F>
+where
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    /// Runs the visitor on every node of the tree, allowing it to skip
+    /// subtrees or stop the walk.
+    ///
+    /// The visitor is called when a node is first reached, before its children
+    /// are requested, even if the walk is in postorder.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator, WalkControl};
+    /// # use std::ops::ControlFlow;
+    /// // node n has children 2n and 2n + 1, up to 15
+    /// let children = |&n: &u32| [2 * n, 2 * n + 1].into_internal_iter().filter(|&c| c <= 15);
+    ///
+    /// let mut visited = Vec::new();
+    /// let result = internal_iterator::walk(1, children)
+    ///     .try_visit(|&n| {
+    ///         visited.push(n);
+    ///         match n {
+    ///             2 => WalkControl::SkipChildren,
+    ///             6 => WalkControl::Break("found 6"),
+    ///             _ => WalkControl::Continue,
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(visited, [1, 2, 3, 6]);
+    /// assert_eq!(result, ControlFlow::Break("found 6"));
+    /// ```
+    pub fn try_visit<R, V>(self, visitor: V) -> ControlFlow<R>
+    where
+        V: FnMut(&N) -> WalkControl<R>,
+    {
+        let Self { root, mut children, postorder } = self;
+        traverse(root, &mut children, postorder, None, visitor, |_| ControlFlow::Continue(()))
+    }
+
+    /// Creates an iterator over the tree that does not descend into children
+    /// of nodes for which `predicate` returns `true`. Such nodes are still
+    /// yielded themselves.
+    ///
+    /// ```
+    /// # use internal_iterator::{InternalIterator, IntoInternalIterator};
+    /// // node n has children 2n and 2n + 1, up to 7
+    /// let children = |&n: &u32| [2 * n, 2 * n + 1].into_internal_iter().filter(|&c| c <= 7);
+    ///
+    /// let pruned = internal_iterator::walk(1, children)
+    ///     .prune(|&n| n == 2)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(pruned, [1, 2, 3, 6, 7]);
+    /// ```
+    pub fn prune<P>(self, predicate: P) -> PrunedWalk<N, F, P>
+    where
+        P: FnMut(&N) -> bool,
+    {
+        PrunedWalk { walk: self, predicate }
+    }
+}
+
+impl<N, F, C> InternalIterator for Walk<N, F>
+where
+    N: Clone,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    type Item = N;
+
+    fn try_for_each<R, G>(self, consumer: G) -> ControlFlow<R>
+    where
+        G: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { root, mut children, postorder } = self;
+        traverse(root, &mut children, postorder, Some(N::clone), |_| WalkControl::Continue, consumer)
+    }
+}
+
+/// An iterator returned by [`Walk::prune`].
+#[derive(Clone)]
+pub struct PrunedWalk<N, F, P> {
+    walk: Walk<N, F>,
+    predicate: P,
+}
+
+impl<N, F, C, P> InternalIterator for PrunedWalk<N, F, P>
+where
+    N: Clone,
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+    P: FnMut(&N) -> bool,
+{
+    type Item = N;
+
+    fn try_for_each<R, G>(self, consumer: G) -> ControlFlow<R>
+    where
+        G: FnMut(Self::Item) -> ControlFlow<R>
+    {
+        let Self { walk: Walk { root, mut children, postorder }, mut predicate } = self;
+        let control = |node: &N| {
+            if predicate(node) {
+                WalkControl::SkipChildren
+            } else {
+                WalkControl::Continue
+            }
+        };
+        traverse(root, &mut children, postorder, Some(N::clone), control, consumer)
+    }
+}
+
+/// Walks the tree, calling `control` when a node is reached and `consumer`
+/// when it is yielded.
+///
+/// In preorder, `clone` is used to yield a node before requesting its children.
+/// Without it the children are requested first, which is only unobservable if
+/// `consumer` never breaks.
+fn traverse<N, F, C, V, G, R>(
+    root: N,
+    children: &mut F,
+    postorder: bool,
+    clone: Option<fn(&N) -> N>,
+    mut control: V,
+    mut consumer: G,
+) -> ControlFlow<R>
+where
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+    V: FnMut(&N) -> WalkControl<R>,
+    G: FnMut(N) -> ControlFlow<R>,
+{
+    // `true` marks nodes whose children have already been pushed
+    let mut stack = Vec::new();
+    stack.push((root, false));
+    while let Some((node, expanded)) = stack.pop() {
+        if expanded {
+            consumer(node)?;
+            continue;
+        }
+        let expand = match control(&node) {
+            WalkControl::Continue => true,
+            WalkControl::SkipChildren => false,
+            WalkControl::Break(value) => return ControlFlow::Break(value),
+        };
+        let node_children = if postorder {
+            let node_children = expand.then(|| children(&node));
+            stack.push((node, true));
+            node_children
+        } else if let (true, Some(clone)) = (expand, clone) {
+            // yield the node first, so that its children are not requested if
+            // the consumer stops on it
+            let parent = clone(&node);
+            consumer(node)?;
+            Some(children(&parent))
+        } else {
+            let node_children = expand.then(|| children(&node));
+            consumer(node)?;
+            node_children
+        };
+        if let Some(node_children) = node_children {
+            let first_child = stack.len();
+            node_children.into_internal_iter().for_each(|child| stack.push((child, false)));
+            stack[first_child..].reverse();
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// Creates an iterator that walks a tree starting at `root`.
+///
+/// The `children` closure is called on every node and returns the children of
+/// that node. Nodes are yielded in preorder (every node before its children),
+/// or in postorder if [`Walk::postorder`] is used.
+///
+/// Unlike a recursive [`InternalIterator::try_for_each`] implementation, this
+/// keeps pending nodes on a heap-allocated stack, so it does not overflow the
+/// call stack on very deep trees.
+///
+/// ```
+/// # use internal_iterator::InternalIterator;
+/// struct Tree {
+///     value: i32,
+///     children: Vec<Tree>,
+/// }
+///
+/// let tree = Tree {
+///     value: 1,
+///     children: vec![
+///         Tree { value: 2, children: vec![] },
+///         Tree { value: 3, children: vec![Tree { value: 4, children: vec![] }] },
+///     ],
+/// };
+///
+/// let preorder = internal_iterator::walk(&tree, |t| &t.children)
+///     .map(|t| t.value)
+///     .collect::<Vec<_>>();
+/// assert_eq!(preorder, [1, 2, 3, 4]);
+///
+/// let postorder = internal_iterator::walk(&tree, |t| &t.children)
+///     .postorder()
+///     .map(|t| t.value)
+///     .collect::<Vec<_>>();
+/// assert_eq!(postorder, [2, 4, 3, 1]);
+/// ```
+pub fn walk<N, F, C>(root: N, children: F) -> Walk<N, F>
+where
+    F: FnMut(&N) -> C,
+    C: IntoInternalIterator<Item = N>,
+{
+    Walk { root, children, postorder: false }
+}